@@ -0,0 +1,118 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::braille::braille_char;
+use crate::cp437::CP437_CHARSET;
+use crate::{BrailleMode, EbcdicCodePage, mixed_braille, no_braille};
+
+/// Characters displayed in the text panel for each byte value.
+///
+/// ```
+/// use colored_hexdump::{CharTable, Hexdump};
+///
+/// let mut table = CharTable::ascii();
+/// table.name = "ASCII with dots".into();
+/// table.chars[0x00] = '.';
+/// println!("{}", Hexdump::xxd().char_table(table).dump(b"\0\0Hello"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CharTable {
+    /// Name of the table, for display
+    pub name: Cow<'static, str>,
+    /// Character of each byte value
+    pub chars: [char; 256],
+}
+
+impl CharTable {
+    pub fn new(name: impl Into<Cow<'static, str>>, chars: [char; 256]) -> Self {
+        CharTable {
+            name: name.into(),
+            chars,
+        }
+    }
+
+    /// Table filled with the character returned by `char` for each byte value
+    pub fn from_fn(name: impl Into<Cow<'static, str>>, mut char: impl FnMut(u8) -> char) -> Self {
+        CharTable::new(name, std::array::from_fn(|byte| char(byte as u8)))
+    }
+
+    /// ASCII characters, with `x` for the bytes of 0x80 and above, see `BrailleMode::None`
+    pub fn ascii() -> Self {
+        CharTable::from_fn("ASCII", no_braille)
+    }
+
+    /// ASCII characters, with Braille patterns for the bytes of 0x80 and above,
+    /// see `BrailleMode::Mixed`
+    pub fn mixed() -> Self {
+        CharTable::from_fn("ASCII and Braille", mixed_braille)
+    }
+
+    /// Distinct glyphs for all the byte values, Braille patterns for the bytes of 0x80 and above,
+    /// see `BrailleMode::All`
+    pub fn braille() -> Self {
+        CharTable::from_fn("Braille", braille_char)
+    }
+
+    /// Glyphs of the IBM PC code page 437: box drawing, accented letters, Greek letters...
+    /// The blank 0x00 and 0xFF are shown as `⋄` and `⍽`.
+    pub fn cp437() -> Self {
+        CharTable::new("CP437", CP437_CHARSET)
+    }
+
+    /// Characters of an EBCDIC code page, with `0`, `_` and `•` for null,
+    /// whitespace and control characters like the ASCII tables
+    pub fn ebcdic(page: EbcdicCodePage) -> Self {
+        let name = match page {
+            EbcdicCodePage::Cp037 => "EBCDIC 037",
+            EbcdicCodePage::Cp1047 => "EBCDIC 1047",
+        };
+        CharTable::from_fn(name, |byte| page.glyph(byte))
+    }
+
+    /// Character of `byte`
+    pub fn get(&self, byte: u8) -> char {
+        self.chars[byte as usize]
+    }
+}
+
+impl From<BrailleMode> for CharTable {
+    fn from(braille: BrailleMode) -> Self {
+        match braille {
+            BrailleMode::None => CharTable::ascii(),
+            BrailleMode::Mixed => CharTable::mixed(),
+            BrailleMode::All => CharTable::braille(),
+            BrailleMode::Cp437 => CharTable::cp437(),
+        }
+    }
+}
+
+impl fmt::Display for CharTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ColorChoice, Hexdump};
+
+    #[test]
+    fn test_char_table() {
+        assert_eq!(CharTable::from(BrailleMode::None).get(b'A'), 'A');
+        assert_eq!(CharTable::from(BrailleMode::None).get(0x80), 'x');
+        assert_eq!(CharTable::from(BrailleMode::Mixed).get(0x80), '⠠');
+        assert_eq!(CharTable::from(BrailleMode::All).get(0x00), '▁');
+        assert_eq!(CharTable::braille().to_string(), "Braille");
+
+        let cp437 = CharTable::from(BrailleMode::Cp437);
+        let bytes = [0x01, b'A', 0x7f, 0x80, 0xb3, 0xc9, 0xe0, 0xe3, 0xfe];
+        let glyphs: String = bytes.iter().map(|&byte| cp437.get(byte)).collect();
+        assert_eq!(glyphs, "☺A⌂Ç│╔απ■");
+
+        let mut table = CharTable::from_fn("dots", |_| '.');
+        table.chars[b'A' as usize] = 'A';
+        let hexdump = Hexdump::xxd().width(4).color(ColorChoice::Never).char_table(table);
+        assert_eq!(hexdump.dump(b"\0A\xff"), "00000000: 0041 ff   .A. \n");
+    }
+}
@@ -0,0 +1,70 @@
+use std::fmt;
+
+/// Class of a byte, selecting its style in the `Theme`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ByteCategory {
+    /// Null bytes
+    Null,
+    /// Whitespace characters
+    Whitespace,
+    /// Printable characters
+    Printable,
+    /// Other characters
+    NonPrintable,
+    /// Bytes outside of the character set
+    High,
+    /// Bytes with a particular meaning, like the delimiters of a protocol
+    Special,
+}
+
+/// Rules sorting the bytes into categories, see `Hexdump::classifier()`.
+///
+/// ```
+/// use colored_hexdump::{AsciiClassifier, ByteCategory, ByteClassifier, Hexdump};
+///
+/// /// SLIP frames, delimited by 0xC0
+/// #[derive(Debug)]
+/// struct Slip;
+///
+/// impl ByteClassifier for Slip {
+///     fn classify(&self, byte: u8) -> ByteCategory {
+///         match byte {
+///             0xc0 => ByteCategory::Special,
+///             _ => AsciiClassifier.classify(byte),
+///         }
+///     }
+///
+///     fn glyph(&self, byte: u8) -> Option<char> {
+///         (byte == 0xc0).then_some('¦')
+///     }
+/// }
+///
+/// println!("{}", Hexdump::hexyl().classifier(Slip).dump(b"\xc0Hello\xc0"));
+/// ```
+pub trait ByteClassifier: fmt::Debug + Send + Sync {
+    /// Category of `byte`
+    fn classify(&self, byte: u8) -> ByteCategory;
+
+    /// Character displayed for `byte` in the text panel,
+    /// `None` to use the one of the `CharTable`
+    fn glyph(&self, byte: u8) -> Option<char> {
+        let _ = byte;
+        None
+    }
+}
+
+/// Default classification, from the ASCII character set
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct AsciiClassifier;
+
+impl ByteClassifier for AsciiClassifier {
+    fn classify(&self, byte: u8) -> ByteCategory {
+        match byte {
+            0x00 => ByteCategory::Null,
+            b'\t' | b'\n' | 0x0c | b'\r' | b' ' => ByteCategory::Whitespace,
+            0x21..0x7f => ByteCategory::Printable,
+            0x01..0x20 | 0x7f => ByteCategory::NonPrintable,
+            _ => ByteCategory::High,
+        }
+    }
+}
@@ -0,0 +1,380 @@
+use std::fmt::{self, Write};
+use std::ops::Range;
+
+use crate::hexdump::{Hexdump, Layout};
+use crate::printer::Printer;
+use crate::rows::{Row, RowState};
+use crate::{Color, Style};
+
+/// Arrangement of the two hexdumps of a `Diff`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLayout {
+    /// Lines of both inputs next to each other
+    SideBySide,
+    /// Lines of the second input below the lines of the first one, when they differ.
+    /// The lines of the first input start with `<`, and those of the second one with `>`.
+    Stacked,
+}
+
+/// Hexdumps of two inputs aligned line by line, with the differing bytes emphasized.
+///
+/// A summary of the differing offsets is written after the hexdumps.
+/// With `Hexdump::squeeze()`, repeated lines identical in both inputs are collapsed into `*`.
+/// The highlights of the `Hexdump` apply to the same offsets of both inputs,
+/// below the emphasis of the differing bytes. When the hexdump isn't colored,
+/// the differing bytes are pointed at by `^` on a line below theirs.
+///
+/// ```
+/// use colored_hexdump::{Diff, DiffLayout, Hexdump};
+///
+/// let diff = Diff::new(Hexdump::xxd().squeeze(true))
+///     .layout(DiffLayout::Stacked)
+///     .dump(b"Hello, World!", b"Hello, world?");
+/// println!("{}", diff);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Diff {
+    options: Hexdump,
+    layout: DiffLayout,
+    emphasis: Style,
+}
+
+impl Default for Diff {
+    fn default() -> Self {
+        Diff::new(Hexdump::hexyl())
+    }
+}
+
+impl Diff {
+    /// Diff of hexdumps formatted with `options`, side by side
+    pub fn new(options: Hexdump) -> Self {
+        Diff {
+            options,
+            layout: DiffLayout::SideBySide,
+            emphasis: Style::new().on(Color::RED).bold(),
+        }
+    }
+
+    /// Set the arrangement of the two hexdumps
+    pub fn layout(mut self, layout: DiffLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Set the style of the differing bytes, bold on a red background by default
+    pub fn emphasis(mut self, emphasis: Style) -> Self {
+        self.emphasis = emphasis;
+        self
+    }
+
+    /// Offsets of the differing bytes, merged in ranges.
+    /// When the inputs don't have the same length, the extra bytes differ.
+    pub fn differences(left: &[u8], right: &[u8]) -> Vec<Range<usize>> {
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+        for offset in 0..left.len().max(right.len()) {
+            if left.get(offset) == right.get(offset) {
+                continue;
+            }
+            match ranges.last_mut() {
+                Some(range) if range.end == offset => range.end += 1,
+                _ => ranges.push(offset..offset + 1),
+            }
+        }
+        ranges
+    }
+
+    /// Diff of `left` and `right`, as a `String`
+    pub fn dump(&self, left: &[u8], right: &[u8]) -> String {
+        let mut out = String::new();
+        self.dump_fmt(&mut out, left, right)
+            .expect("writing to a String doesn't fail");
+        out
+    }
+
+    /// Write the diff of `left` and `right` to `out`, line by line
+    pub fn dump_fmt(&self, out: &mut impl Write, left: &[u8], right: &[u8]) -> fmt::Result {
+        let (left, right) = (self.options.addressable(left), self.options.addressable(right));
+        let differences = Diff::differences(left, right);
+        let last_byte = (self.options.base)
+            .saturating_add(left.len().max(right.len()).saturating_sub(1) as u64);
+
+        let printer = Printer::new(&self.options, last_byte);
+        // the lines are squeezed here, only when identical in both inputs
+        let options = self.options.clone().squeeze(false);
+        let mut left_rows = RowState::new(&options);
+        let mut right_rows = RowState::new(&options);
+
+        // width of the lines of the left side, to align the right side
+        let blank = RowState::new(&options).row(&[], true).expect("the last line is never hidden");
+        let width = visible_width(single(|out| printer.row(out, &blank))?.trim_end_matches('\n'));
+
+        let header = |out: &mut String| printer.header(out);
+        let header = self.lines(width, &header, &header, true)?;
+        out.write_str(&header)?;
+
+        let colored = self.options.depth().is_some();
+        let (mut left, mut right) = (left, right);
+        // last line identical in both inputs, empty if the last line differed
+        let mut previous: Vec<u8> = Vec::new();
+        let mut squeezing = false;
+        loop {
+            let capacity = left_rows.capacity();
+            let (left_line, left_rest) = left.split_at(left.len().min(capacity));
+            let (right_line, right_rest) = right.split_at(right.len().min(capacity));
+            (left, right) = (left_rest, right_rest);
+            let last = left.is_empty() && right.is_empty();
+
+            let identical = left_line == right_line;
+            // like with `Hexdump::squeeze()`, but only for lines identical in both inputs
+            let squeezed = self.options.squeeze
+                && identical
+                && previous == left_line
+                && !last
+                && !left_rows.highlighted(left_line.len());
+
+            let differing: Vec<bool> = (0..left_line.len().max(right_line.len()))
+                .map(|i| left_line.get(i) != right_line.get(i))
+                .collect();
+            let (Some(mut left_row), Some(mut right_row)) = (
+                left_rows.emphasized_row(left_line, last, &differing, self.emphasis),
+                right_rows.emphasized_row(right_line, last, &differing, self.emphasis),
+            ) else {
+                unreachable!("lines are only hidden when squeezing");
+            };
+
+            previous.clear();
+            if identical && left_line.len() == capacity {
+                previous.extend_from_slice(left_line);
+            }
+
+            if !(squeezed && squeezing) {
+                (left_row.squeezed, right_row.squeezed) = (squeezed, squeezed);
+                // without colors, the differing bytes are marked on a line below
+                let markers = |out: &mut String, row: &Row| match identical || colored {
+                    true => Ok(()),
+                    false => {
+                        let (hex, text) = left_rows.markers(row, &differing);
+                        printer.markers(out, &hex, &text)
+                    }
+                };
+                let lines = match (self.layout, identical) {
+                    (DiffLayout::SideBySide, _) => self.lines(
+                        width,
+                        &|out| printer.row(out, &left_row).and_then(|()| markers(out, &left_row)),
+                        &|out| printer.row(out, &right_row).and_then(|()| markers(out, &right_row)),
+                        identical,
+                    )?,
+                    (DiffLayout::Stacked, true) => labelled(' ', &single(|out| printer.row(out, &left_row))?),
+                    (DiffLayout::Stacked, false) => {
+                        let mut lines = labelled('<', &single(|out| printer.row(out, &left_row))?);
+                        lines += &labelled('>', &single(|out| printer.row(out, &right_row))?);
+                        lines += &labelled(' ', &single(|out| markers(out, &right_row))?);
+                        lines
+                    }
+                };
+                out.write_str(&lines)?;
+            }
+            squeezing = squeezed;
+
+            if last {
+                break;
+            }
+        }
+
+        let footer = |out: &mut String| printer.footer(out);
+        let footer = self.lines(width, &footer, &footer, true)?;
+        out.write_str(&footer)?;
+        if self.options.layout == Layout::Hexyl {
+            out.write_char('\n')?;
+        }
+        self.summary(out, &differences)
+    }
+
+    /// Lines written by `left` and `right`, side by side, or only `left` when stacked.
+    /// The left lines are padded to `width`, and differing lines are marked between the two sides.
+    /// Only the first line is marked, the others being the markers of the bytes or the legend.
+    fn lines(
+        &self,
+        width: usize,
+        left: &dyn Fn(&mut String) -> fmt::Result,
+        right: &dyn Fn(&mut String) -> fmt::Result,
+        identical: bool,
+    ) -> Result<String, fmt::Error> {
+        let left = single(left)?;
+        if self.layout == DiffLayout::Stacked {
+            return Ok(labelled(' ', &left));
+        }
+        let right = single(right)?;
+
+        let mut lines = String::new();
+        for (i, (left, right)) in left.split_inclusive('\n').zip(right.split_inclusive('\n')).enumerate() {
+            let marker = match identical || i > 0 {
+                true => Style::new().paint(' '),
+                false => self.printed_emphasis().paint('≠'),
+            };
+            let left = left.trim_end_matches('\n');
+            let padding = width.saturating_sub(visible_width(left));
+            write!(lines, "{}{:padding$} {} {}", left, "", marker, right)?;
+        }
+        Ok(lines)
+    }
+
+    /// Emphasis of the markers, only kept when the hexdump is colored
+    fn printed_emphasis(&self) -> Style {
+        match self.options.depth() {
+            Some(depth) => Style {
+                foreground: self.emphasis.background.or(self.emphasis.foreground),
+                background: None,
+                bold: self.emphasis.bold,
+            }
+            .downgrade(depth),
+            None => Style::new(),
+        }
+    }
+
+    /// Number of differing bytes, and their addresses
+    fn summary(&self, out: &mut impl Write, differences: &[Range<usize>]) -> fmt::Result {
+        if differences.is_empty() {
+            return writeln!(out, "no differences");
+        }
+
+        let count: usize = differences.iter().map(|range| range.len()).sum();
+        let plural = if count == 1 { "" } else { "s" };
+        write!(out, "{} differing byte{}:", count, plural)?;
+        for (i, range) in differences.iter().enumerate() {
+            let separator = if i == 0 { " " } else { ", " };
+            // the end of a range at the top of the address space is past `u64::MAX`
+            let start = self.options.base as u128 + range.start as u128;
+            match range.len() {
+                1 => write!(out, "{}{}", separator, self.address(start))?,
+                len => write!(
+                    out,
+                    "{}{}..{}",
+                    separator,
+                    self.address(start),
+                    self.address(start + len as u128),
+                )?,
+            }
+        }
+        writeln!(out)
+    }
+
+    /// Address of the summary, in hexadecimal like in the hexdump
+    fn address(&self, address: u128) -> String {
+        match self.options.uppercase {
+            true => format!("0x{:X}", address),
+            false => format!("0x{:x}", address),
+        }
+    }
+}
+
+/// Number of characters of `line` displayed, without the escape sequences
+fn visible_width(line: &str) -> usize {
+    let mut escaped = false;
+    line.chars()
+        .filter(|&c| {
+            let visible = !escaped && c != '\x1b';
+            escaped = (escaped || c == '\x1b') && c != 'm';
+            visible
+        })
+        .count()
+}
+
+/// `lines` with each line starting with `label`, to tell the inputs apart when stacked
+fn labelled(label: char, lines: &str) -> String {
+    lines.split_inclusive('\n').map(|line| format!("{} {}", label, line)).collect()
+}
+
+/// Output of `write`, as a `String`
+fn single(write: impl FnOnce(&mut String) -> fmt::Result) -> Result<String, fmt::Error> {
+    let mut out = String::new();
+    write(&mut out)?;
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ColorChoice, ColorDepth, Theme};
+
+    #[test]
+    fn test_differences() {
+        assert_eq!(Diff::differences(b"abc", b"abc"), []);
+        assert_eq!(Diff::differences(b"abcdef", b"aXXdeY"), [1..3, 5..6]);
+        assert_eq!(Diff::differences(b"ab", b"abcd"), vec![2..4]);
+    }
+
+    #[test]
+    fn test_diff() {
+        let expected: Vec<u8> = [[0u8; 12].as_slice(), b"Hello"].concat();
+        let received: Vec<u8> = [[0u8; 12].as_slice(), b"HeLLo!"].concat();
+        let options = Hexdump::xxd().width(4).squeeze(true).color(ColorChoice::Never);
+
+        assert_eq!(
+            Diff::new(options.clone()).dump(&expected, &received),
+            "00000000: 0000 0000 0000   00000000: 0000 0000 0000\n\
+             *                          *\n\
+             0000000c: 4865 6c6c Hell ≠ 0000000c: 4865 4c4c HeLL\n\
+             \x20              ^^^^   ^^                  ^^^^   ^^\n\
+             00000010: 6f        o    ≠ 00000010: 6f21      o!  \n\
+             \x20           ^^       ^                 ^^       ^\n\
+             3 differing bytes: 0xe..0x10, 0x11\n"
+        );
+        assert_eq!(
+            Diff::new(options.clone().base(0x100)).layout(DiffLayout::Stacked).dump(&expected, &received),
+            "  00000100: 0000 0000 0000\n\
+             \x20 *\n\
+             < 0000010c: 4865 6c6c Hell\n\
+             > 0000010c: 4865 4c4c HeLL\n\
+             \x20                ^^^^   ^^\n\
+             < 00000110: 6f        o   \n\
+             > 00000110: 6f21      o!  \n\
+             \x20             ^^       ^\n\
+             3 differing bytes: 0x10e..0x110, 0x111\n"
+        );
+
+        let hexyl = Diff::new(Hexdump::hexyl().width(4).color(ColorChoice::Never));
+        assert_eq!(
+            hexyl.dump(b"ab", b"ab"),
+            "┌────────┬─────────────┬────┐   ┌────────┬─────────────┬────┐\n\
+             │00000000│ 61 62       │ab  │   │00000000│ 61 62       │ab  │\n\
+             └────────┴─────────────┴────┘   └────────┴─────────────┴────┘\n\
+             no differences\n"
+        );
+        assert_eq!(
+            hexyl.layout(DiffLayout::Stacked).dump(b"ab", b"aB"),
+            "  ┌────────┬─────────────┬────┐\n\
+             < │00000000│ 61 62       │ab  │\n\
+             > │00000000│ 61 42       │aB  │\n\
+             \x20 │        │    ^^       │ ^  │\n\
+             \x20 └────────┴─────────────┴────┘\n\
+             1 differing byte: 0x1\n"
+        );
+
+        let top = Diff::new(options.clone().base(u64::MAX - 1)).dump(b"abc", b"aXY");
+        assert!(top.ends_with("\n1 differing byte: 0xffffffffffffffff\n"), "{top}");
+
+        let highlighted = options.highlight(4..6, Style::new().on(Color::BLUE), "length");
+        assert_eq!(
+            Diff::new(highlighted).dump(&expected[..12], &received[..12]),
+            "00000000: 0000 0000 0000   00000000: 0000 0000 0000\n\
+             00000004: 0000 0000 0000   00000004: 0000 0000 0000\n\
+             00000008: 0000 0000 0000   00000008: 0000 0000 0000\n\
+             \x20  length                     length\n\
+             no differences\n"
+        );
+    }
+
+    #[test]
+    fn test_diff_emphasis() {
+        let diff = Diff::new(Hexdump::xxd().width(4).theme(Theme::PLAIN).color_depth(ColorDepth::Ansi256))
+            .emphasis(Style::new().on(Color::BLUE))
+            .dump(b"ab", b"aB");
+        assert_eq!(
+            diff,
+            "00000000: 61\x1b[44m62\x1b[0m      a\x1b[44mb\x1b[0m   \x1b[34m≠\x1b[0m \
+             00000000: 61\x1b[44m42\x1b[0m      a\x1b[44mB\x1b[0m  \n\
+             1 differing byte: 0x1\n"
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{ByteCategory, ByteClassifier};
+
+/// EBCDIC code pages of IBM mainframes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EbcdicCodePage {
+    /// Code page 037, US and Canada
+    Cp037,
+    /// Code page 1047, Latin-1 for Open Systems, used by z/OS Unix
+    Cp1047,
+}
+
+impl EbcdicCodePage {
+    /// Unicode character of `byte`, control characters included
+    pub fn decode(self, byte: u8) -> char {
+        match self {
+            EbcdicCodePage::Cp037 => CP037[byte as usize],
+            EbcdicCodePage::Cp1047 => CP1047[byte as usize],
+        }
+    }
+
+    /// Character displayed for `byte` in the text panel, like the ASCII tables:
+    /// `0` for null, `_` for whitespace and `•` for control characters
+    pub(crate) fn glyph(self, byte: u8) -> char {
+        match self.decode(byte) {
+            '\0' => '0',
+            ' ' => ' ',
+            c if c.is_whitespace() => '_',
+            c if c.is_control() => '•',
+            // the soft hyphen is usually invisible
+            '\u{ad}' => '-',
+            c => c,
+        }
+    }
+}
+
+/// Classification of the bytes from their EBCDIC character, see `Hexdump::ebcdic()`.
+/// Characters outside of ASCII, like accented letters, are classified as `ByteCategory::High`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EbcdicClassifier(pub EbcdicCodePage);
+
+impl ByteClassifier for EbcdicClassifier {
+    fn classify(&self, byte: u8) -> ByteCategory {
+        match self.0.decode(byte) {
+            '\0' => ByteCategory::Null,
+            c if c.is_whitespace() => ByteCategory::Whitespace,
+            c if c.is_control() => ByteCategory::NonPrintable,
+            c if c.is_ascii() => ByteCategory::Printable,
+            _ => ByteCategory::High,
+        }
+    }
+}
+
+/// Unicode characters of EBCDIC code page 037, 16 bytes per line
+pub(crate) const CP037: [char; 256] = [
+    '\u{0}', '\u{1}', '\u{2}', '\u{3}', '\u{9c}', '\u{9}', '\u{86}', '\u{7f}', '\u{97}', '\u{8d}', '\u{8e}', '\u{b}', '\u{c}', '\u{d}', '\u{e}', '\u{f}',
+    '\u{10}', '\u{11}', '\u{12}', '\u{13}', '\u{9d}', '\u{85}', '\u{8}', '\u{87}', '\u{18}', '\u{19}', '\u{92}', '\u{8f}', '\u{1c}', '\u{1d}', '\u{1e}', '\u{1f}',
+    '\u{80}', '\u{81}', '\u{82}', '\u{83}', '\u{84}', '\u{a}', '\u{17}', '\u{1b}', '\u{88}', '\u{89}', '\u{8a}', '\u{8b}', '\u{8c}', '\u{5}', '\u{6}', '\u{7}',
+    '\u{90}', '\u{91}', '\u{16}', '\u{93}', '\u{94}', '\u{95}', '\u{96}', '\u{4}', '\u{98}', '\u{99}', '\u{9a}', '\u{9b}', '\u{14}', '\u{15}', '\u{9e}', '\u{1a}',
+    ' ', '\u{a0}', 'â', 'ä', 'à', 'á', 'ã', 'å', 'ç', 'ñ', '¢', '.', '<', '(', '+', '|',
+    '&', 'é', 'ê', 'ë', 'è', 'í', 'î', 'ï', 'ì', 'ß', '!', '$', '*', ')', ';', '¬',
+    '-', '/', 'Â', 'Ä', 'À', 'Á', 'Ã', 'Å', 'Ç', 'Ñ', '¦', ',', '%', '_', '>', '?',
+    'ø', 'É', 'Ê', 'Ë', 'È', 'Í', 'Î', 'Ï', 'Ì', '`', ':', '#', '@', '\'', '=', '"',
+    'Ø', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', '«', '»', 'ð', 'ý', 'þ', '±',
+    '°', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 'ª', 'º', 'æ', '¸', 'Æ', '¤',
+    'µ', '~', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '¡', '¿', 'Ð', 'Ý', 'Þ', '®',
+    '^', '£', '¥', '·', '©', '§', '¶', '¼', '½', '¾', '[', ']', '¯', '¨', '´', '×',
+    '{', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', '\u{ad}', 'ô', 'ö', 'ò', 'ó', 'õ',
+    '}', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', '¹', 'û', 'ü', 'ù', 'ú', 'ÿ',
+    '\\', '÷', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', '²', 'Ô', 'Ö', 'Ò', 'Ó', 'Õ',
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '³', 'Û', 'Ü', 'Ù', 'Ú', '\u{9f}',
+];
+
+/// Unicode characters of EBCDIC code page 1047, 16 bytes per line.
+/// It only differs from 037 for `[`, `]`, `^`, `¬`, `Ý` and `¨`.
+pub(crate) const CP1047: [char; 256] = [
+    '\u{0}', '\u{1}', '\u{2}', '\u{3}', '\u{9c}', '\u{9}', '\u{86}', '\u{7f}', '\u{97}', '\u{8d}', '\u{8e}', '\u{b}', '\u{c}', '\u{d}', '\u{e}', '\u{f}',
+    '\u{10}', '\u{11}', '\u{12}', '\u{13}', '\u{9d}', '\u{85}', '\u{8}', '\u{87}', '\u{18}', '\u{19}', '\u{92}', '\u{8f}', '\u{1c}', '\u{1d}', '\u{1e}', '\u{1f}',
+    '\u{80}', '\u{81}', '\u{82}', '\u{83}', '\u{84}', '\u{a}', '\u{17}', '\u{1b}', '\u{88}', '\u{89}', '\u{8a}', '\u{8b}', '\u{8c}', '\u{5}', '\u{6}', '\u{7}',
+    '\u{90}', '\u{91}', '\u{16}', '\u{93}', '\u{94}', '\u{95}', '\u{96}', '\u{4}', '\u{98}', '\u{99}', '\u{9a}', '\u{9b}', '\u{14}', '\u{15}', '\u{9e}', '\u{1a}',
+    ' ', '\u{a0}', 'â', 'ä', 'à', 'á', 'ã', 'å', 'ç', 'ñ', '¢', '.', '<', '(', '+', '|',
+    '&', 'é', 'ê', 'ë', 'è', 'í', 'î', 'ï', 'ì', 'ß', '!', '$', '*', ')', ';', '^',
+    '-', '/', 'Â', 'Ä', 'À', 'Á', 'Ã', 'Å', 'Ç', 'Ñ', '¦', ',', '%', '_', '>', '?',
+    'ø', 'É', 'Ê', 'Ë', 'È', 'Í', 'Î', 'Ï', 'Ì', '`', ':', '#', '@', '\'', '=', '"',
+    'Ø', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', '«', '»', 'ð', 'ý', 'þ', '±',
+    '°', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 'ª', 'º', 'æ', '¸', 'Æ', '¤',
+    'µ', '~', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '¡', '¿', 'Ð', '[', 'Þ', '®',
+    '¬', '£', '¥', '·', '©', '§', '¶', '¼', '½', '¾', 'Ý', '¨', '¯', ']', '´', '×',
+    '{', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', '\u{ad}', 'ô', 'ö', 'ò', 'ó', 'õ',
+    '}', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', '¹', 'û', 'ü', 'ù', 'ú', 'ÿ',
+    '\\', '÷', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', '²', 'Ô', 'Ö', 'Ò', 'Ó', 'Õ',
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '³', 'Û', 'Ü', 'Ù', 'Ú', '\u{9f}',
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ColorChoice, ColorDepth, Hexdump};
+
+    #[test]
+    fn test_ebcdic() {
+        let record = b"\xc8\x85\x93\x93\x96\x40\xad\xbd\x25";
+        let decode = |page: EbcdicCodePage| -> String {
+            record.iter().map(|&byte| page.decode(byte)).collect()
+        };
+        assert_eq!(decode(EbcdicCodePage::Cp037), "Hello Ý¨\n");
+        assert_eq!(decode(EbcdicCodePage::Cp1047), "Hello []\n");
+
+        let classifier = EbcdicClassifier(EbcdicCodePage::Cp037);
+        assert_eq!(classifier.classify(0x00), ByteCategory::Null);
+        assert_eq!(classifier.classify(0x40), ByteCategory::Whitespace);
+        assert_eq!(classifier.classify(0x25), ByteCategory::Whitespace);
+        assert_eq!(classifier.classify(0x07), ByteCategory::NonPrintable);
+        assert_eq!(classifier.classify(0xc1), ByteCategory::Printable);
+        assert_eq!(classifier.classify(0x41), ByteCategory::Whitespace);
+        assert_eq!(classifier.classify(0x51), ByteCategory::High);
+
+        let hexdump = Hexdump::xxd().ebcdic(EbcdicCodePage::Cp1047).color(ColorChoice::Never);
+        assert_eq!(
+            hexdump.dump(record),
+            "00000000: c885 9393 9640 adbd 25                  Hello []_       \n"
+        );
+        let hexdump = Hexdump::xxd().ebcdic(EbcdicCodePage::Cp1047).color_depth(ColorDepth::Ansi256);
+        let colored = hexdump.dump(b"\xc1\x51");
+        assert!(colored.contains("\x1b[36mc1\x1b[0m\x1b[33m51\x1b[0m"));
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::VecDeque;
+
+/// Shannon entropy of `total` bytes whose values occur `counts` times, relative to
+/// the maximum reachable with that many bytes: from 0 for repeated bytes, to 1 for random data
+pub(crate) fn entropy(counts: &[usize; 256], total: usize) -> f64 {
+    let max = (total.min(256) as f64).log2();
+    if max == 0.0 {
+        return 0.0;
+    }
+
+    let bits: f64 = counts
+        .iter()
+        .filter(|&&count| count > 0)
+        .map(|&count| {
+            let p = count as f64 / total as f64;
+            -p * p.log2()
+        })
+        .sum();
+    (bits / max).min(1.0)
+}
+
+/// Relative entropy of a line, see `entropy()`
+pub(crate) fn line_entropy(bytes: &[u8]) -> f64 {
+    let mut counts = [0; 256];
+    for &byte in bytes {
+        counts[byte as usize] += 1;
+    }
+    entropy(&counts, bytes.len())
+}
+
+/// Last bytes of the input, to compute the entropy over a sliding window
+#[derive(Debug, Clone)]
+pub(crate) struct Window {
+    size: usize,
+    bytes: VecDeque<u8>,
+    counts: Box<[usize; 256]>,
+}
+
+impl Window {
+    pub(crate) fn new(size: usize) -> Self {
+        Window {
+            size,
+            // not preallocated, the size can be as large as the whole input
+            bytes: VecDeque::new(),
+            counts: Box::new([0; 256]),
+        }
+    }
+
+    /// Add `byte` to the window, dropping the oldest byte once the window is full.
+    /// Returns the relative entropy of the window, `byte` included.
+    pub(crate) fn push(&mut self, byte: u8) -> f64 {
+        if self.bytes.len() == self.size.max(1)
+            && let Some(oldest) = self.bytes.pop_front()
+        {
+            self.counts[oldest as usize] -= 1;
+        }
+        self.bytes.push_back(byte);
+        self.counts[byte as usize] += 1;
+
+        entropy(&self.counts, self.bytes.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entropy() {
+        assert_eq!(line_entropy(b""), 0.0);
+        assert_eq!(line_entropy(b"aaaa"), 0.0);
+        assert_eq!(line_entropy(b"abcd"), 1.0);
+        assert_eq!(line_entropy(b"aabb"), 0.5);
+
+        let all_bytes: Vec<u8> = (0..=u8::MAX).cycle().take(1024).collect();
+        assert_eq!(line_entropy(&all_bytes), 1.0);
+
+        let mut window = Window::new(2);
+        let entropies: Vec<f64> = b"aabba".iter().map(|&byte| window.push(byte)).collect();
+        assert_eq!(entropies, [0.0, 0.0, 1.0, 0.0, 1.0]);
+
+        let mut window = Window::new(usize::MAX);
+        for end in 1..=all_bytes.len() {
+            assert_eq!(window.push(all_bytes[end - 1]), line_entropy(&all_bytes[..end]));
+        }
+    }
+}
@@ -0,0 +1,880 @@
+use std::borrow::Cow;
+use std::io::IsTerminal;
+use std::ops::Range;
+use std::sync::Arc;
+use std::{fmt, io};
+
+use crate::{
+    AsciiClassifier, BrailleMode, ByteClassifier, CharTable, ColorChoice, ColorDepth, ColorScheme,
+    EbcdicClassifier, EbcdicCodePage, Highlight, Style, Theme,
+};
+use crate::printer::{IoAdapter, Printer};
+use crate::rows::{RowState, Rows};
+
+/// Layout of the produced hexdump
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    /// Hexdump with borders, in the style of hexyl
+    Hexyl,
+    /// Classic hexdump in the style of xxd
+    Xxd,
+}
+
+/// Configurable hexdump.
+///
+/// `Hexdump::hexyl()` and `Hexdump::xxd()` are the presets used by
+/// `hexdump()` and `xxd()`, every option can then be changed with the builder methods.
+///
+/// ```
+/// use colored_hexdump::{BrailleMode, Hexdump};
+///
+/// let hexdump = Hexdump::xxd()
+///     .braille(BrailleMode::None)
+///     .dump(b"Hello, World!");
+/// println!("{}", hexdump);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Hexdump {
+    pub(crate) layout: Layout,
+    pub(crate) chars: CharTable,
+    pub(crate) width: usize,
+    pub(crate) group: usize,
+    pub(crate) base: u64,
+    pub(crate) address_width: Option<usize>,
+    pub(crate) uppercase: bool,
+    pub(crate) squeeze: bool,
+    pub(crate) theme: Theme,
+    pub(crate) color: ColorChoice,
+    pub(crate) color_depth: Option<ColorDepth>,
+    pub(crate) scheme: ColorScheme,
+    pub(crate) classifier: Arc<dyn ByteClassifier>,
+    pub(crate) highlights: Vec<Highlight>,
+}
+
+impl Default for Hexdump {
+    fn default() -> Self {
+        Self::hexyl()
+    }
+}
+
+impl Hexdump {
+    /// Hexdump with borders, in the style of hexyl
+    pub fn hexyl() -> Self {
+        Hexdump {
+            layout: Layout::Hexyl,
+            chars: CharTable::mixed(),
+            width: 0x10,
+            group: 2,
+            base: 0,
+            address_width: None,
+            uppercase: false,
+            squeeze: false,
+            theme: Theme::DEFAULT,
+            color: ColorChoice::Always,
+            color_depth: None,
+            scheme: ColorScheme::Classes,
+            classifier: Arc::new(AsciiClassifier),
+            highlights: Vec::new(),
+        }
+    }
+
+    /// Hexdump in the style of xxd
+    pub fn xxd() -> Self {
+        Hexdump {
+            layout: Layout::Xxd,
+            ..Self::hexyl()
+        }
+    }
+
+    /// Set the layout of the hexdump
+    pub fn layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Set the characters used to display bytes in the text panel to a built-in table
+    pub fn braille(mut self, braille: BrailleMode) -> Self {
+        self.chars = CharTable::from(braille);
+        self
+    }
+
+    /// Set the characters used to display bytes in the text panel, `CharTable::mixed()` by default
+    pub fn char_table(mut self, table: CharTable) -> Self {
+        self.chars = table;
+        self
+    }
+
+    /// Set the number of bytes displayed on each line, 16 by default
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is 0.
+    pub fn width(mut self, width: usize) -> Self {
+        assert!(width > 0, "a hexdump line must contain at least one byte");
+        self.width = width;
+        self
+    }
+
+    /// Set the number of bytes grouped together in the xxd layout, 2 by default.
+    /// Like `xxd -g`, a value of 0 disables grouping.
+    pub fn group(mut self, group: usize) -> Self {
+        self.group = group;
+        self
+    }
+
+    /// Set the address of the first byte, like `xxd -o`.
+    ///
+    /// Columns stay aligned to the absolute address: when `base` isn't a multiple
+    /// of the width, the first line is only partially filled. The hexdump ends
+    /// at the highest address, `u64::MAX`: the bytes that would follow aren't displayed.
+    pub fn base(mut self, base: u64) -> Self {
+        self.base = base;
+        self
+    }
+
+    /// Set the minimum number of hexadecimal digits of the address column.
+    ///
+    /// The column is widened when the highest address shown needs more digits.
+    /// By default, the minimum is 8 digits.
+    pub fn address_width(mut self, address_width: usize) -> Self {
+        self.address_width = Some(address_width);
+        self
+    }
+
+    /// Use uppercase hexadecimal digits, for both the bytes and the addresses
+    pub fn uppercase(mut self, uppercase: bool) -> Self {
+        self.uppercase = uppercase;
+        self
+    }
+
+    /// Collapse identical consecutive lines into a single `*` line,
+    /// like `hexdump -C`. The last line is always displayed.
+    pub fn squeeze(mut self, squeeze: bool) -> Self {
+        self.squeeze = squeeze;
+        self
+    }
+
+    /// Set the colors of the hexdump, `Theme::DEFAULT` by default
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Set whether the hexdump is colored, `ColorChoice::Always` by default.
+    ///
+    /// `ColorChoice::Auto` checks if the output is a terminal, when it is known:
+    /// - `print()`, `eprint()`, `dump_stream()` and `for_stream()` check their stream,
+    /// - `dump_io()` and `dump_reader()` consider that their output isn't a terminal,
+    /// - `dump()`, `dump_fmt()` and `HexDisplay` check stdout.
+    pub fn color(mut self, color: ColorChoice) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Set the colors supported by the terminal, detected from `COLORTERM` and `TERM` by default.
+    ///
+    /// Colors of the theme that aren't supported are replaced by the closest ones available.
+    pub fn color_depth(mut self, depth: ColorDepth) -> Self {
+        self.color_depth = Some(depth);
+        self
+    }
+
+    /// Set the rules sorting the bytes into categories, `AsciiClassifier` by default.
+    ///
+    /// The category of a byte selects its style in the theme,
+    /// and the classifier can also replace its character in the text panel.
+    pub fn classifier(mut self, classifier: impl ByteClassifier + 'static) -> Self {
+        self.classifier = Arc::new(classifier);
+        self
+    }
+
+    /// Highlight the bytes at offsets `range` of the input with `style`, usually a background color.
+    ///
+    /// A legend listing the labels is written after the hexdump.
+    /// Where highlights overlap, the last one added wins.
+    /// Lines with highlighted bytes are never squeezed.
+    ///
+    /// ```
+    /// use colored_hexdump::{Color, Hexdump, Style};
+    ///
+    /// let hexdump = Hexdump::hexyl()
+    ///     .highlight(0..2, Style::new().on(Color::BLUE), "length")
+    ///     .highlight(2..4, Style::new().on(Color::RED), "checksum")
+    ///     .dump(b"\x00\x05\x12\x34Hello");
+    /// println!("{}", hexdump);
+    /// ```
+    pub fn highlight(mut self, range: Range<usize>, style: Style, label: impl Into<String>) -> Self {
+        self.highlights.push(Highlight::new(range, style, label));
+        self
+    }
+
+    /// Add all the `highlights`, see `highlight()`
+    pub fn highlights(mut self, highlights: impl IntoIterator<Item = Highlight>) -> Self {
+        self.highlights.extend(highlights);
+        self
+    }
+
+    /// Decode the text panel with an EBCDIC code page, and classify the bytes from their
+    /// EBCDIC character. Sets both `char_table()` and `classifier()`.
+    pub fn ebcdic(self, page: EbcdicCodePage) -> Self {
+        self.char_table(CharTable::ebcdic(page))
+            .classifier(EbcdicClassifier(page))
+    }
+
+    /// Set how the color of each byte is chosen, `ColorScheme::Classes` by default
+    pub fn color_scheme(mut self, scheme: ColorScheme) -> Self {
+        self.scheme = scheme;
+        self
+    }
+
+    /// Produce the hexdump of `bytes`
+    pub fn dump(&self, bytes: &[u8]) -> String {
+        let mut output = String::new();
+        self.dump_fmt(&mut output, bytes)
+            .expect("writing to a String doesn't fail");
+        output
+    }
+
+    /// Iterate over the rows of the hexdump of `bytes`.
+    ///
+    /// Squeezed lines are represented by a single row. An empty input gives a single empty row.
+    ///
+    /// ```
+    /// use colored_hexdump::Hexdump;
+    ///
+    /// let hexdump = Hexdump::xxd();
+    /// for row in hexdump.rows(b"Hello, World!") {
+    ///     println!("{:08x} | {} | {}", row.address, row.hex, row.text);
+    /// }
+    /// ```
+    pub fn rows<'a>(&'a self, bytes: &'a [u8]) -> Rows<'a> {
+        Rows::new(self, self.addressable(bytes))
+    }
+
+    /// Lazily format the hexdump of `bytes`, see `HexDisplay`
+    pub fn display<'a>(&'a self, bytes: &'a [u8]) -> HexDisplay<'a> {
+        HexDisplay {
+            hexdump: Cow::Borrowed(self),
+            bytes,
+        }
+    }
+
+    /// Write the hexdump of `bytes` to `out`, line by line
+    pub fn dump_fmt(&self, out: &mut impl fmt::Write, bytes: &[u8]) -> fmt::Result {
+        let bytes = self.addressable(bytes);
+        let last_byte = self.base.saturating_add(bytes.len().saturating_sub(1) as u64);
+        let printer = Printer::new(self, last_byte);
+
+        printer.header(out)?;
+        for row in self.rows(bytes) {
+            printer.row(out, &row)?;
+        }
+        printer.footer(out)
+    }
+
+    /// Write the hexdump of `bytes` to `out`, line by line.
+    ///
+    /// Each line is written with a single call to `out`. With `ColorChoice::Auto`, `out`
+    /// isn't considered as a terminal: see `dump_stream()` for stdout, stderr or files.
+    pub fn dump_io(&self, out: &mut impl io::Write, bytes: &[u8]) -> io::Result<()> {
+        let options = self.for_terminal(false);
+        let mut adapter = IoAdapter::new(out);
+        let result = options.dump_fmt(&mut adapter, bytes);
+        adapter.finish(result)
+    }
+
+    /// Write the hexdump of `bytes` to `out`, line by line, like `dump_io()`.
+    /// With `ColorChoice::Auto`, the colors depend on whether `out` is a terminal.
+    pub fn dump_stream(&self, out: &mut (impl io::Write + IsTerminal), bytes: &[u8]) -> io::Result<()> {
+        self.for_stream(out).dump_io(out, bytes)
+    }
+
+    /// Write the hexdump of everything read from `reader` to `out`, line by line.
+    ///
+    /// Only two lines of input are kept in memory. As the length of the input
+    /// isn't known in advance, the address column has 16 digits by default, enough
+    /// for any address. When `address_width()` is set, the column has that width
+    /// instead, or more if the base address needs it: longer addresses, past the end
+    /// of the column, then break the alignment.
+    ///
+    /// With `ColorChoice::Auto`, `out` isn't considered as a terminal:
+    /// resolve the colors for the actual output with `for_stream()`.
+    pub fn dump_reader(&self, out: &mut impl io::Write, mut reader: impl io::Read) -> io::Result<()> {
+        let options = self.for_terminal(false);
+        let mut out = IoAdapter::new(out);
+        // the column fits any address, unless a width is set
+        let last_byte = match self.address_width {
+            Some(_) => self.base,
+            None => u64::MAX,
+        };
+        let printer = Printer::new(&options, last_byte);
+        let mut rows = RowState::new(&options);
+        let result = printer.header(&mut out);
+        out.check(result)?;
+
+        // the next line is read in advance, to know when the last one is written
+        let mut line = vec![0; self.width];
+        let mut next = vec![0; self.width];
+        // bytes left before the highest address, where the hexdump ends
+        let mut room = (u64::MAX - self.base) as u128 + 1;
+        let mut len = read_line(&mut reader, &mut line[..rows.capacity()], &mut room)?;
+
+        loop {
+            let next_len = match len {
+                0 => 0,
+                _ => read_line(&mut reader, &mut next, &mut room)?,
+            };
+            if let Some(row) = rows.row(&line[..len], next_len == 0) {
+                let result = printer.row(&mut out, &row);
+                out.check(result)?;
+            }
+
+            if next_len == 0 {
+                break;
+            }
+            std::mem::swap(&mut line, &mut next);
+            len = next_len;
+        }
+
+        let result = printer.footer(&mut out);
+        out.finish(result)
+    }
+
+    /// Print the hexdump of `bytes` to stdout
+    pub fn print(&self, bytes: &[u8]) -> io::Result<()> {
+        let stdout = io::stdout();
+        self.for_stream(&stdout).print_to(&mut stdout.lock(), bytes)
+    }
+
+    /// Print the hexdump of `bytes` to stderr
+    pub fn eprint(&self, bytes: &[u8]) -> io::Result<()> {
+        let stderr = io::stderr();
+        self.for_stream(&stderr).print_to(&mut stderr.lock(), bytes)
+    }
+
+    /// Same options, with `ColorChoice::Auto` resolved for an output written to `stream`.
+    ///
+    /// ```
+    /// use std::io;
+    /// use colored_hexdump::{ColorChoice, Hexdump};
+    ///
+    /// let stdout = io::stdout();
+    /// let hexdump = Hexdump::xxd().color(ColorChoice::Auto).for_stream(&stdout);
+    /// hexdump.dump_reader(&mut stdout.lock(), &b"Hello, World!"[..]).unwrap();
+    /// ```
+    pub fn for_stream(&self, stream: &impl IsTerminal) -> Hexdump {
+        self.for_terminal(stream.is_terminal()).into_owned()
+    }
+
+    /// Same options, with `ColorChoice::Auto` resolved for an output that is a `terminal` or not
+    fn for_terminal(&self, terminal: bool) -> Cow<'_, Hexdump> {
+        match self.color {
+            ColorChoice::Auto => Cow::Owned(Hexdump {
+                color: ColorChoice::auto(terminal),
+                ..self.clone()
+            }),
+            _ => Cow::Borrowed(self),
+        }
+    }
+
+    /// Write the hexdump, making sure it ends with a new line
+    fn print_to(&self, out: &mut impl io::Write, bytes: &[u8]) -> io::Result<()> {
+        self.dump_io(out, bytes)?;
+        match self.layout {
+            Layout::Hexyl => writeln!(out),
+            Layout::Xxd => Ok(()),
+        }
+    }
+
+    /// Colors actually available, `None` when the hexdump isn't colored.
+    /// Unless it is set, the depth is detected from the environment.
+    pub(crate) fn depth(&self) -> Option<ColorDepth> {
+        match self.color {
+            ColorChoice::Never => None,
+            ColorChoice::Always => Some(self.color_depth.unwrap_or_else(ColorDepth::detect)),
+            ColorChoice::Auto => self.for_terminal(io::stdout().is_terminal()).depth(),
+        }
+    }
+
+    /// Theme actually used, depending on the color choice
+    pub(crate) fn styles(&self) -> Theme {
+        match self.depth() {
+            Some(depth) => self.theme.downgrade(depth),
+            None => Theme::PLAIN,
+        }
+    }
+
+    /// Start of `bytes` with an address, up to `u64::MAX`
+    pub(crate) fn addressable<'b>(&self, bytes: &'b [u8]) -> &'b [u8] {
+        let room = (u64::MAX - self.base) as u128 + 1;
+        &bytes[..bytes.len().min(room.try_into().unwrap_or(usize::MAX))]
+    }
+
+    /// Number of digits used to display the addresses, up to `last_address`
+    pub(crate) fn address_digits(&self, last_address: u64) -> usize {
+        let significant = (u64::BITS - last_address.leading_zeros()).div_ceil(4) as usize;
+        significant.max(self.address_width.unwrap_or(8))
+    }
+}
+
+/// Hexdump formatted when displayed, without building the whole `String` first.
+///
+/// ```
+/// use colored_hexdump::{HexDisplay, Hexdump};
+///
+/// let bytes = b"Hello, World!";
+/// println!("{}", HexDisplay::new(bytes));
+/// println!("{}", HexDisplay::new(bytes).options(Hexdump::xxd()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct HexDisplay<'a> {
+    hexdump: Cow<'a, Hexdump>,
+    bytes: &'a [u8],
+}
+
+impl<'a> HexDisplay<'a> {
+    /// Display `bytes` with the default options, see `Hexdump::hexyl()`
+    pub fn new(bytes: &'a [u8]) -> Self {
+        HexDisplay {
+            hexdump: Cow::Owned(Hexdump::default()),
+            bytes,
+        }
+    }
+
+    /// Set the options used to format the hexdump
+    pub fn options(mut self, hexdump: Hexdump) -> Self {
+        self.hexdump = Cow::Owned(hexdump);
+        self
+    }
+}
+
+impl fmt::Display for HexDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.hexdump.dump_fmt(f, self.bytes)
+    }
+}
+
+/// Fill `buf` from `reader`, stopping early only at the end of the input
+/// or once `room` bytes have been read
+fn read_line(reader: &mut impl io::Read, buf: &mut [u8], room: &mut u128) -> io::Result<usize> {
+    let end = (*room).min(buf.len() as u128) as usize;
+    let buf = &mut buf[..end];
+    let mut len = 0;
+    while len < buf.len() {
+        match reader.read(&mut buf[len..]) {
+            Ok(0) => break,
+            Ok(read) => len += read,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+            Err(error) => return Err(error),
+        }
+    }
+    *room -= len as u128;
+    Ok(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ByteCategory, Color, Row, Style};
+    use crate::theme::gradient;
+
+    /// Remove the ANSI escape sequences to compare the layout only
+    fn strip_ansi(colored: &str) -> String {
+        let mut output = String::new();
+        let mut chars = colored.chars();
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                chars.by_ref().find(|c| *c == 'm');
+            } else {
+                output.push(c);
+            }
+        }
+        output
+    }
+
+    #[test]
+    fn test_width() {
+        let bytes: Vec<u8> = (0x41..0x55).collect();
+
+        let result = Hexdump::hexyl().width(8).dump(&bytes);
+        assert_eq!(strip_ansi(&result), "\
+┌────────┬─────────────────────────┬────────┐
+│00000000│ 41 42 43 44 45 46 47 48 │ABCDEFGH│
+│00000008│ 49 4a 4b 4c 4d 4e 4f 50 │IJKLMNOP│
+│00000010│ 51 52 53 54             │QRST    │
+└────────┴─────────────────────────┴────────┘");
+
+        let result = Hexdump::hexyl().width(12).dump(&bytes);
+        assert_eq!(strip_ansi(&result), "\
+┌────────┬─────────────────────────┬─────────────┬────────┬────┐
+│00000000│ 41 42 43 44 45 46 47 48 │ 49 4a 4b 4c │ABCDEFGH│IJKL│
+│0000000c│ 4d 4e 4f 50 51 52 53 54 │             │MNOPQRST│    │
+└────────┴─────────────────────────┴─────────────┴────────┴────┘");
+
+        let result = Hexdump::xxd().width(5).dump(&bytes[..8]);
+        assert_eq!(strip_ansi(&result), "\
+00000000: 4142 4344 45 ABCDE
+00000005: 4647 48      FGH  
+");
+    }
+
+    #[test]
+    fn test_group() {
+        let bytes: Vec<u8> = (0x41..0x51).collect();
+
+        let result = Hexdump::xxd().group(4).dump(&bytes[..10]);
+        assert_eq!(strip_ansi(&result), "\
+00000000: 41424344 45464748 494a              ABCDEFGHIJ      
+");
+
+        let result = Hexdump::xxd().group(0).width(8).dump(&bytes);
+        assert_eq!(strip_ansi(&result), "\
+00000000: 4142434445464748 ABCDEFGH
+00000008: 494a4b4c4d4e4f50 IJKLMNOP
+");
+
+        let result = Hexdump::xxd().group(1).width(4).dump(&bytes[..4]);
+        assert_eq!(strip_ansi(&result), "00000000: 41 42 43 44 ABCD\n");
+    }
+
+    #[test]
+    fn test_base() {
+        let bytes: Vec<u8> = (0x41..0x55).collect();
+
+        let result = Hexdump::xxd().base(0x2000_0000).dump(&bytes[..4]);
+        assert_eq!(strip_ansi(&result), "20000000: 4142 4344                               ABCD            \n");
+
+        let result = Hexdump::hexyl().base(0x1c).dump(&bytes);
+        assert_eq!(strip_ansi(&result), "\
+┌────────┬─────────────────────────┬─────────────────────────┬────────┬────────┐
+│00000010│                         │             41 42 43 44 │        │    ABCD│
+│00000020│ 45 46 47 48 49 4a 4b 4c │ 4d 4e 4f 50 51 52 53 54 │EFGHIJKL│MNOPQRST│
+└────────┴─────────────────────────┴─────────────────────────┴────────┴────────┘");
+
+        // the hexdump ends at the highest address
+        let top = Hexdump::xxd().width(4).base(u64::MAX - 5).color(ColorChoice::Never);
+        let expected = "\
+fffffffffffffff8:      4142   AB
+fffffffffffffffc: 4344 4546 CDEF
+";
+        assert_eq!(top.dump(&bytes), expected);
+        let mut output = Vec::new();
+        top.clone().address_width(16).dump_reader(&mut output, &bytes[..]).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), expected);
+        assert_eq!(top.rows(&bytes).count(), 2);
+    }
+
+    #[test]
+    fn test_address_width() {
+        let bytes: Vec<u8> = (0x41..0x45).collect();
+
+        let result = Hexdump::hexyl().width(4).base(0xffff_fffe).dump(&bytes);
+        assert_eq!(strip_ansi(&result), "\
+┌─────────┬─────────────┬────┐
+│0fffffffc│       41 42 │  AB│
+│100000000│ 43 44       │CD  │
+└─────────┴─────────────┴────┘");
+
+        let result = Hexdump::xxd().width(4).address_width(4).dump(&bytes);
+        assert_eq!(strip_ansi(&result), "0000: 4142 4344 ABCD\n");
+
+        let bytes = [0x41; 0x108];
+        let result = Hexdump::hexyl().width(4).address_width(2).squeeze(true).dump(&bytes);
+        assert_eq!(strip_ansi(&result), "\
+┌───┬─────────────┬────┐
+│000│ 41 41 41 41 │AAAA│
+│*  │             │    │
+│104│ 41 41 41 41 │AAAA│
+└───┴─────────────┴────┘");
+    }
+
+    #[test]
+    fn test_uppercase() {
+        let bytes = [0xde, 0xad, 0xbe, 0xef];
+
+        let result = Hexdump::xxd().width(4).base(0xab0).uppercase(true).dump(&bytes);
+        assert_eq!(strip_ansi(&result), "00000AB0: DEAD BEEF ⢵⡧⢭⣾\n");
+
+        let result = Hexdump::hexyl().width(4).uppercase(true).dump(&bytes);
+        assert!(strip_ansi(&result).contains("│00000000│ DE AD BE EF │⢵⡧⢭⣾│"));
+    }
+
+    #[test]
+    fn test_squeeze() {
+        let mut bytes = vec![0u8; 0x40];
+        bytes.extend_from_slice(b"ABCD");
+        bytes.extend_from_slice(&[0u8; 0x1c]);
+
+        let result = Hexdump::xxd().width(8).squeeze(true).dump(&bytes);
+        assert_eq!(strip_ansi(&result), "\
+00000000: 0000 0000 0000 0000 00000000
+*
+00000040: 4142 4344 0000 0000 ABCD0000
+00000048: 0000 0000 0000 0000 00000000
+*
+00000058: 0000 0000 0000 0000 00000000
+");
+
+        let result = Hexdump::hexyl().width(8).squeeze(true).dump(&bytes[..0x20]);
+        assert_eq!(strip_ansi(&result), "\
+┌────────┬─────────────────────────┬────────┐
+│00000000│ 00 00 00 00 00 00 00 00 │00000000│
+│*       │                         │        │
+│00000018│ 00 00 00 00 00 00 00 00 │00000000│
+└────────┴─────────────────────────┴────────┘");
+    }
+
+    #[test]
+    fn test_dump_io() {
+        let bytes: Vec<u8> = (0..=u8::MAX).collect();
+
+        for hexdump in [Hexdump::hexyl(), Hexdump::xxd().squeeze(true)] {
+            let mut output = Vec::new();
+            hexdump.dump_io(&mut output, &bytes).unwrap();
+            assert_eq!(String::from_utf8(output).unwrap(), hexdump.dump(&bytes));
+        }
+    }
+
+    /// Reader returning at most 3 bytes per call
+    struct ShortReader<'a>(&'a [u8]);
+
+    impl io::Read for ShortReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let len = buf.len().min(self.0.len()).min(3);
+            buf[..len].copy_from_slice(&self.0[..len]);
+            self.0 = &self.0[len..];
+            Ok(len)
+        }
+    }
+
+    #[test]
+    fn test_dump_reader() {
+        let mut bytes = vec![0u8; 0x40];
+        bytes.extend(0..=u8::MAX);
+
+        for hexdump in [Hexdump::hexyl().base(0x1234), Hexdump::xxd().squeeze(true), Hexdump::xxd()] {
+            for len in [0, 5, 0x10, bytes.len()] {
+                let mut output = Vec::new();
+                hexdump.dump_reader(&mut output, ShortReader(&bytes[..len])).unwrap();
+                let expected = hexdump.clone().address_width(16).dump(&bytes[..len]);
+                assert_eq!(String::from_utf8(output).unwrap(), expected);
+            }
+        }
+
+        // the addresses past 4 GiB still fit in the column
+        let hexdump = Hexdump::hexyl().width(4).base(0xffff_fffc).color(ColorChoice::Never);
+        let mut output = Vec::new();
+        hexdump.dump_reader(&mut output, ShortReader(b"ABCDEFGH")).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), "\
+┌────────────────┬─────────────┬────┐
+│00000000fffffffc│ 41 42 43 44 │ABCD│
+│0000000100000000│ 45 46 47 48 │EFGH│
+└────────────────┴─────────────┴────┘");
+    }
+
+    #[test]
+    fn test_display() {
+        let bytes: Vec<u8> = (0..=u8::MAX).collect();
+
+        assert_eq!(HexDisplay::new(&bytes).to_string(), crate::hexdump(&bytes));
+
+        let hexdump = Hexdump::xxd().width(8).uppercase(true);
+        assert_eq!(hexdump.display(&bytes).to_string(), hexdump.dump(&bytes));
+        assert_eq!(HexDisplay::new(&bytes).options(hexdump.clone()).to_string(), hexdump.dump(&bytes));
+    }
+
+    #[test]
+    fn test_rows() {
+        let mut bytes = vec![0u8; 0x20];
+        bytes.extend_from_slice(b"ABC");
+
+        let hexdump = Hexdump::xxd().width(8).base(0x1004).squeeze(true);
+        let rows: Vec<Row> = hexdump.rows(&bytes).collect();
+
+        let addresses: Vec<u64> = rows.iter().map(|row| row.address).collect();
+        assert_eq!(addresses, [0x1000, 0x1008, 0x1010, 0x1020]);
+
+        assert_eq!(rows[0].lead, 4);
+        assert_eq!(rows[0].bytes, [0; 4]);
+        assert_eq!(strip_ansi(&rows[0].hex), "          0000 0000");
+        assert_eq!(strip_ansi(&rows[0].text), "    0000");
+        assert!(!rows[1].squeezed);
+        assert!(rows[2].squeezed);
+        assert_eq!(rows[3].bytes, b"\0\0\0\0ABC");
+        assert_eq!(strip_ansi(&rows[3].text), "0000ABC ");
+    }
+
+    #[test]
+    fn test_theme() {
+        let theme = Theme {
+            printable: Style::new().fg(Color::RED),
+            address: Style::new(),
+            border: Style::new().fg(Color::BLUE),
+            separator: Style::new().bold(),
+            ..Theme::DEFAULT
+        };
+
+        let result = Hexdump::hexyl().width(9).theme(theme).color_depth(ColorDepth::Ansi256).dump(b"A");
+        assert_eq!(result, "\
+\x1b[34m┌\x1b[0m\x1b[34m────────\x1b[0m\x1b[34m┬─────────────────────────┬────┬────────┬─┐\x1b[0m
+\x1b[34m│\x1b[0m00000000\x1b[34m│\x1b[0m \x1b[31m41\x1b[0m                      \x1b[1m│\x1b[0m    \x1b[34m│\x1b[0m\x1b[31mA\x1b[0m       \x1b[1m│\x1b[0m \x1b[34m│\x1b[0m
+\x1b[34m└\x1b[0m\x1b[34m────────\x1b[0m\x1b[34m┴─────────────────────────┴────┴────────┴─┘\x1b[0m");
+    }
+
+    #[test]
+    fn test_no_color() {
+        let bytes: Vec<u8> = (0..=u8::MAX).collect();
+
+        for hexdump in [Hexdump::hexyl().theme(Theme::HEXYL), Hexdump::xxd().squeeze(true)] {
+            let plain = hexdump.clone().color(ColorChoice::Never).dump(&bytes);
+            assert!(!plain.contains('\x1b'));
+            assert_eq!(plain, strip_ansi(&hexdump.dump(&bytes)));
+        }
+    }
+
+    #[test]
+    fn test_classifier() {
+        #[derive(Debug)]
+        struct Delimiters;
+
+        impl ByteClassifier for Delimiters {
+            fn classify(&self, byte: u8) -> ByteCategory {
+                match byte {
+                    0x7e | 0xc0 => ByteCategory::Special,
+                    _ => AsciiClassifier.classify(byte),
+                }
+            }
+
+            fn glyph(&self, byte: u8) -> Option<char> {
+                (byte == 0xc0).then_some('¦')
+            }
+        }
+
+        let hexdump = Hexdump::xxd()
+            .width(4)
+            .classifier(Delimiters)
+            .color_depth(ColorDepth::Ansi256);
+        assert_eq!(
+            hexdump.dump(b"~A\xc0"),
+            "\x1b[38;5;242m00000000: \x1b[0m\x1b[1;31m7e\x1b[0m\x1b[36m41\x1b[0m \x1b[1;31mc0\x1b[0m   \
+             \x1b[1;31m~\x1b[0m\x1b[36mA\x1b[0m\x1b[1;31m¦\x1b[0m \n"
+        );
+    }
+
+    #[test]
+    fn test_gradient() {
+        let hexdump = Hexdump::xxd()
+            .color_scheme(ColorScheme::Gradient)
+            .color_depth(ColorDepth::TrueColor);
+
+        let colored = hexdump.dump(&[0x00, 0x41, 0xff]);
+        let Color::Rgb(r, g, b) = gradient(0x41) else { unreachable!() };
+        assert!(colored.contains(&format!("\x1b[38;2;{r};{g};{b}m41\x1b[0m")));
+        assert!(colored.contains(&format!("\x1b[38;2;{r};{g};{b}mA\x1b[0m")));
+        assert_eq!(
+            strip_ansi(&colored),
+            Hexdump::xxd().color(ColorChoice::Never).dump(&[0x00, 0x41, 0xff])
+        );
+
+        let plain = hexdump.color(ColorChoice::Never).dump(&[0x00, 0x41, 0xff]);
+        assert!(!plain.contains('\x1b'));
+    }
+
+    #[test]
+    fn test_entropy() {
+        let bytes: Vec<u8> = [[0u8; 16].as_slice(), &(0..16).collect::<Vec<u8>>()].concat();
+        let rgb = |value| match gradient(value) {
+            Color::Rgb(r, g, b) => format!("\x1b[38;2;{r};{g};{b}m"),
+            color => unreachable!("{color:?}"),
+        };
+
+        let lines = Hexdump::xxd()
+            .color_scheme(ColorScheme::LineEntropy)
+            .color_depth(ColorDepth::TrueColor)
+            .dump(&bytes);
+        let lines: Vec<&str> = lines.lines().collect();
+        assert_eq!(lines[0].matches(&rgb(0x00)).count(), 32);
+        assert_eq!(lines[1].matches(&rgb(0xff)).count(), 32);
+
+        let window = Hexdump::xxd()
+            .color_scheme(ColorScheme::Entropy(4))
+            .color_depth(ColorDepth::TrueColor)
+            .dump(&bytes);
+        let window: Vec<&str> = window.lines().collect();
+        assert_eq!(window[0].matches(&rgb(0x00)).count(), 32);
+        assert!(window[1].starts_with(&format!("\x1b[38;5;242m00000010: \x1b[0m{}00", rgb(0x00))));
+        assert_eq!(window[1].matches(&rgb(0xff)).count(), 2 * 13);
+        assert_eq!(strip_ansi(window[1]), strip_ansi(lines[1]));
+    }
+
+    #[test]
+    fn test_highlight() {
+        let hexdump = Hexdump::xxd()
+            .width(4)
+            .color_depth(ColorDepth::Ansi256)
+            .highlight(1..3, Style::new().on(Color::BLUE), "length")
+            .highlight(2..3, Style::new().fg(Color::WHITE).on(Color::RED), "type")
+            .highlight(5..6, Style::new().on(Color::BLUE), "length");
+        assert_eq!(
+            hexdump.dump(b"\x00\x01A\x00\x00\x00"),
+            "\x1b[38;5;242m00000000: \x1b[0m\x1b[38;5;242m00\x1b[0m\x1b[35;44m01\x1b[0m \
+             \x1b[37;41m41\x1b[0m\x1b[38;5;242m00\x1b[0m \
+             \x1b[38;5;242m0\x1b[0m\x1b[35;44m•\x1b[0m\x1b[37;41mA\x1b[0m\x1b[38;5;242m0\x1b[0m\n\
+             \x1b[38;5;242m00000004: \x1b[0m\x1b[38;5;242m00\x1b[0m\x1b[38;5;242;44m00\x1b[0m      \
+             \x1b[38;5;242m0\x1b[0m\x1b[38;5;242;44m0\x1b[0m  \n\
+             \x1b[44m  \x1b[0m length\n\
+             \x1b[37;41m  \x1b[0m type\n"
+        );
+
+        let plain = hexdump.clone().base(2).squeeze(true).color(ColorChoice::Never);
+        assert_eq!(
+            plain.dump(&[0; 12]),
+            "00000000:      0000   00\n\
+             00000004: 0000 0000 0000\n\
+             *\n\
+             0000000c: 0000      00  \n\
+             \x20  length\n\
+             \x20  type\n"
+        );
+
+        let hexyl = Hexdump::hexyl()
+            .color_depth(ColorDepth::Ansi256)
+            .highlight(0..1, Style::new().on(Color::BLUE), "first");
+        assert!(hexyl.dump(b"A").ends_with("┘\n\x1b[44m  \x1b[0m first"));
+    }
+
+    #[test]
+    fn test_auto_color() {
+        let hexdump = Hexdump::xxd().color(ColorChoice::Auto);
+        let mut out = Vec::new();
+        hexdump.dump_io(&mut out, b"Hello").unwrap();
+
+        // only colored when forced by the environment, as a `Vec` isn't a terminal
+        let resolved = hexdump.clone().color(ColorChoice::auto(false));
+        let expected = resolved.color_depth(ColorDepth::detect()).dump(b"Hello");
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+        assert_eq!(hexdump.for_stream(&io::stdout()).color, ColorChoice::Auto.resolve(&io::stdout()));
+    }
+
+    #[test]
+    fn test_color_depth() {
+        let theme = Theme {
+            printable: Style::new().fg(Color::Rgb(255, 135, 0)),
+            ..Theme::DEFAULT
+        };
+        let hexdump = Hexdump::xxd().theme(theme);
+
+        let truecolor = hexdump.clone().color_depth(ColorDepth::TrueColor).dump(b"A");
+        assert!(truecolor.contains("\x1b[38;2;255;135;0mA\x1b[0m"));
+        let ansi256 = hexdump.clone().color_depth(ColorDepth::Ansi256).dump(b"A");
+        assert!(ansi256.contains("\x1b[38;5;208mA\x1b[0m"));
+        assert!(ansi256.contains("\x1b[38;5;242m00000000: \x1b[0m"));
+        let ansi16 = hexdump.clone().color_depth(ColorDepth::Ansi16).dump(b"A");
+        assert!(ansi16.contains("\x1b[33mA\x1b[0m"));
+        assert!(ansi16.contains("\x1b[90m00000000: \x1b[0m"));
+
+        // without a depth set, the colors supported are detected
+        let detected = hexdump.clone().color_depth(ColorDepth::detect()).dump(b"A");
+        assert_eq!(hexdump.dump(b"A"), detected);
+    }
+}
@@ -0,0 +1,146 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, BinaryHeap};
+use std::ops::Range;
+
+use crate::{ColorDepth, Style};
+
+/// Bytes of the input displayed with a particular style, see `Hexdump::highlight()`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Highlight {
+    /// Offsets of the bytes in the input, regardless of the base address
+    pub range: Range<usize>,
+    /// Style of the bytes, usually with a background color
+    pub style: Style,
+    /// Label of the highlight in the legend
+    pub label: String,
+}
+
+impl Highlight {
+    pub fn new(range: Range<usize>, style: Style, label: impl Into<String>) -> Self {
+        Highlight {
+            range,
+            style,
+            label: label.into(),
+        }
+    }
+}
+
+/// Highlights walked along the input, finding those of each byte without going through all of them
+#[derive(Debug, Clone)]
+pub(crate) struct Highlighter<'a> {
+    highlights: &'a [Highlight],
+    /// indices of the non-empty highlights, sorted by start offset
+    starts: Vec<usize>,
+    /// number of `starts` already reached
+    started: usize,
+    /// indices of the highlights containing the current offset
+    active: BTreeSet<usize>,
+    /// ends of the `active` highlights, the closest one first
+    ends: BinaryHeap<Reverse<(usize, usize)>>,
+}
+
+impl<'a> Highlighter<'a> {
+    pub(crate) fn new(highlights: &'a [Highlight]) -> Self {
+        let mut starts: Vec<usize> = (0..highlights.len())
+            .filter(|&i| !highlights[i].range.is_empty())
+            .collect();
+        starts.sort_by_key(|&i| highlights[i].range.start);
+
+        Highlighter {
+            highlights,
+            starts,
+            started: 0,
+            active: BTreeSet::new(),
+            ends: BinaryHeap::new(),
+        }
+    }
+
+    /// Move the cursor forward to `offset`
+    fn seek(&mut self, offset: usize) {
+        while let Some(&i) = self.starts.get(self.started)
+            && self.highlights[i].range.start <= offset
+        {
+            self.active.insert(i);
+            self.ends.push(Reverse((self.highlights[i].range.end, i)));
+            self.started += 1;
+        }
+        while let Some(&Reverse((end, i))) = self.ends.peek()
+            && end <= offset
+        {
+            self.active.remove(&i);
+            self.ends.pop();
+        }
+    }
+
+    /// Style of the byte at `offset`, highlighted by the last of the highlights containing it.
+    /// The offsets are only expected to increase.
+    pub(crate) fn style(&mut self, offset: usize, style: Style, depth: ColorDepth) -> Style {
+        self.seek(offset);
+        match self.active.last() {
+            Some(&i) => layered(style, self.highlights[i].style.downgrade(depth)),
+            None => style,
+        }
+    }
+
+    /// Whether any of the bytes in `range` is highlighted, `range` starting after the previous offsets
+    pub(crate) fn overlaps(&mut self, range: Range<usize>) -> bool {
+        if range.is_empty() {
+            return false;
+        }
+        self.seek(range.start);
+        let next = self.starts.get(self.started);
+        !self.active.is_empty() || next.is_some_and(|&i| self.highlights[i].range.start < range.end)
+    }
+}
+
+/// `over` applied on top of `style`, keeping the colors `over` doesn't set
+pub(crate) fn layered(style: Style, over: Style) -> Style {
+    Style {
+        foreground: over.foreground.or(style.foreground),
+        background: over.background.or(style.background),
+        bold: over.bold || style.bold,
+    }
+}
+
+/// Highlights of the legend, without repeating the same style and label
+pub(crate) fn legend(highlights: &[Highlight]) -> Vec<&Highlight> {
+    let mut legend: Vec<&Highlight> = Vec::new();
+    for highlight in highlights {
+        let known = legend
+            .iter()
+            .any(|entry| entry.style == highlight.style && entry.label == highlight.label);
+        if !known {
+            legend.push(highlight);
+        }
+    }
+    legend
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Color;
+
+    #[test]
+    fn test_highlighter() {
+        let (blue, red) = (Style::new().on(Color::BLUE), Style::new().on(Color::RED));
+        let highlights = [
+            Highlight::new(4..8, blue, ""),
+            Highlight::new(0..6, red, ""),
+            Highlight::new(7..7, red, ""),
+            Highlight::new(10..12, blue, ""),
+        ];
+        let mut highlighter = Highlighter::new(&highlights);
+        let styles: Vec<Style> = (0..12)
+            .map(|offset| highlighter.style(offset, Style::new(), ColorDepth::Ansi256))
+            .collect();
+        let plain = Style::new();
+        assert_eq!(styles, [red, red, red, red, red, red, blue, blue, plain, plain, blue, blue]);
+
+        let mut highlighter = Highlighter::new(&highlights);
+        assert!(highlighter.overlaps(2..4));
+        assert!(!highlighter.overlaps(8..10));
+        assert!(highlighter.overlaps(9..11));
+        assert!(!highlighter.overlaps(12..16));
+    }
+}
@@ -0,0 +1,193 @@
+use std::fmt::{self, Write};
+use std::io;
+
+use crate::hexdump::{Hexdump, Layout};
+use crate::rows::{PANEL_WIDTH, Row};
+use crate::highlight::legend;
+use crate::{ColorDepth, Style, Theme};
+
+/// Write the rows of a hexdump in its layout
+pub(crate) struct Printer<'a> {
+    options: &'a Hexdump,
+    /// number of digits of the address column
+    digits: usize,
+    /// styles actually used, depending on the color choice
+    theme: Theme,
+    /// colors available, `None` when the hexdump isn't colored
+    depth: Option<ColorDepth>,
+}
+
+impl<'a> Printer<'a> {
+    /// Printer for a dump whose last byte is displayed at `last_byte`
+    pub(crate) fn new(options: &'a Hexdump, last_byte: u64) -> Self {
+        Printer {
+            options,
+            digits: options.address_digits(last_byte - last_byte % options.width as u64),
+            theme: options.styles(),
+            depth: options.depth(),
+        }
+    }
+
+    pub(crate) fn header(&self, out: &mut impl Write) -> fmt::Result {
+        if self.options.layout == Layout::Hexyl {
+            let border = self.theme.border.paint("─".repeat(self.digits));
+            writeln!(out, "{}", self.frame(border, '─', '┌', '┬', '┐'))?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn footer(&self, out: &mut impl Write) -> fmt::Result {
+        if self.options.layout == Layout::Hexyl {
+            let border = self.theme.border.paint("─".repeat(self.digits));
+            write!(out, "{}", self.frame(border, '─', '└', '┴', '┘'))?;
+        }
+        self.legend(out)
+    }
+
+    /// Labels of the highlights, below the hexyl box or after the xxd lines
+    fn legend(&self, out: &mut impl Write) -> fmt::Result {
+        for highlight in legend(&self.options.highlights) {
+            let style = match self.depth {
+                Some(depth) => highlight.style.downgrade(depth),
+                None => Style::new(),
+            };
+            let line = format!("{} {}", style.paint("  "), highlight.label);
+            match self.options.layout {
+                Layout::Hexyl => write!(out, "\n{}", line)?,
+                Layout::Xxd => writeln!(out, "{}", line)?,
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn row(&self, out: &mut impl Write, row: &Row) -> fmt::Result {
+        if row.squeezed {
+            return self.write_squeezed(out);
+        }
+
+        let theme = &self.theme;
+        let digits = self.digits;
+        let address = match self.options.uppercase {
+            true => format!("{:0digits$X}", row.address),
+            false => format!("{:0digits$x}", row.address),
+        };
+
+        let address = match self.options.layout {
+            Layout::Hexyl => theme.address.paint(address).to_string(),
+            Layout::Xxd => theme.address.paint(format_args!("{}: ", address)).to_string(),
+        };
+        self.line(out, address, &row.hex, &row.text)
+    }
+
+    /// Line of panels written by `RowState::markers()`, below a row and without address
+    pub(crate) fn markers(&self, out: &mut impl Write, hex: &str, text: &str) -> fmt::Result {
+        match self.options.layout {
+            Layout::Hexyl => self.line(out, " ".repeat(self.digits), hex, text),
+            Layout::Xxd => {
+                let mut line = String::new();
+                self.line(&mut line, " ".repeat(self.digits + 2), hex, text)?;
+                writeln!(out, "{}", line.trim_end())
+            }
+        }
+    }
+
+    /// Line of the layout with the given address column and panels
+    fn line(&self, out: &mut impl Write, address: impl fmt::Display, hex: &str, text: &str) -> fmt::Result {
+        match self.options.layout {
+            Layout::Hexyl => {
+                let border = self.theme.border.paint('│');
+                writeln!(out, "{border}{}{border} {} {border}{}{border}", address, hex, text)
+            }
+            Layout::Xxd => writeln!(out, "{}{} {}", address, hex, text),
+        }
+    }
+
+    /// Line replacing identical consecutive lines
+    fn write_squeezed(&self, out: &mut impl Write) -> fmt::Result {
+        let address = self.theme.address;
+        let digits = self.digits;
+        match self.options.layout {
+            Layout::Hexyl => {
+                let marker = format!("{:<digits$}", '*');
+                writeln!(out, "{}", self.frame(address.paint(marker), ' ', '│', '│', '│'))
+            }
+            Layout::Xxd => writeln!(out, "{}", address.paint('*')),
+        }
+    }
+
+    /// Line of the hexyl layout with the cells filled by `fill`,
+    /// sized to the address and line width
+    fn frame(
+        &self,
+        address: impl fmt::Display,
+        fill: char,
+        left: char,
+        junction: char,
+        right: char,
+    ) -> String {
+        let width = self.options.width;
+        let panels: Vec<usize> = (0..width)
+            .step_by(PANEL_WIDTH)
+            .map(|start| PANEL_WIDTH.min(width - start))
+            .collect();
+
+        let fill = fill.to_string();
+        let hex_panels: Vec<String> = panels.iter().map(|len| fill.repeat(3 * len + 1)).collect();
+        let text_panels: Vec<String> = panels.iter().map(|len| fill.repeat(*len)).collect();
+        let junction = junction.to_string();
+
+        let border = self.theme.border;
+        let cells = format!(
+            "{junction}{}{junction}{}{right}",
+            hex_panels.join(&junction),
+            text_panels.join(&junction),
+        );
+        format!("{}{}{}", border.paint(left), address, border.paint(cells))
+    }
+}
+
+/// `fmt::Write` adapter over an `io::Write`, writing each line with a single call
+pub(crate) struct IoAdapter<'a, W: io::Write> {
+    inner: &'a mut W,
+    line: String,
+    error: io::Result<()>,
+}
+
+impl<'a, W: io::Write> IoAdapter<'a, W> {
+    pub(crate) fn new(inner: &'a mut W) -> Self {
+        IoAdapter {
+            inner,
+            line: String::new(),
+            error: Ok(()),
+        }
+    }
+
+    /// Convert the result of the formatting, returning the underlying error
+    pub(crate) fn check(&mut self, result: fmt::Result) -> io::Result<()> {
+        match result {
+            Ok(()) => Ok(()),
+            Err(fmt::Error) => {
+                std::mem::replace(&mut self.error, Ok(()))?;
+                Err(io::Error::other("formatter error"))
+            }
+        }
+    }
+
+    /// Write the remaining output, and convert the result of the formatting
+    pub(crate) fn finish(mut self, result: fmt::Result) -> io::Result<()> {
+        self.check(result)?;
+        self.inner.write_all(self.line.as_bytes())
+    }
+}
+
+impl<W: io::Write> Write for IoAdapter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.line.push_str(s);
+
+        if s.ends_with('\n') {
+            self.error = self.inner.write_all(self.line.as_bytes());
+            self.line.clear();
+        }
+        self.error.as_ref().map_err(|_| fmt::Error).copied()
+    }
+}
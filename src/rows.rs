@@ -0,0 +1,287 @@
+use std::fmt::Write;
+
+use crate::hexdump::{Hexdump, Layout};
+use crate::entropy::{Window, line_entropy};
+use crate::highlight::{Highlighter, layered};
+use crate::theme::gradient;
+use crate::{ColorDepth, ColorScheme, Style, Theme};
+
+/// Number of bytes between two separators in the hexyl layout
+pub(crate) const PANEL_WIDTH: usize = 8;
+
+/// A line of a hexdump, see `Hexdump::rows()`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row<'a> {
+    /// Address of the first cell of the row
+    pub address: u64,
+    /// Number of empty cells before the first byte, when the base address isn't aligned
+    pub lead: usize,
+    /// Bytes displayed on the row
+    pub bytes: &'a [u8],
+    /// Colored hexadecimal cells, with the separators of the layout
+    pub hex: String,
+    /// Colored text panel, with the separators of the layout
+    pub text: String,
+    /// Whether the row stands for lines identical to the previous row.
+    /// These rows are displayed as `*`, and their `hex` and `text` are blank.
+    pub squeezed: bool,
+}
+
+/// Iterator over the rows of a hexdump, see `Hexdump::rows()`
+#[derive(Debug, Clone)]
+pub struct Rows<'a> {
+    state: RowState<'a>,
+    remaining: &'a [u8],
+    finished: bool,
+}
+
+impl<'a> Rows<'a> {
+    pub(crate) fn new(options: &'a Hexdump, bytes: &'a [u8]) -> Self {
+        Rows {
+            state: RowState::new(options),
+            remaining: bytes,
+            finished: false,
+        }
+    }
+}
+
+impl<'a> Iterator for Rows<'a> {
+    type Item = Row<'a>;
+
+    fn next(&mut self) -> Option<Row<'a>> {
+        // an empty input still gets an (empty) row
+        while !self.finished {
+            let (line, rest) = self
+                .remaining
+                .split_at(self.remaining.len().min(self.state.capacity()));
+            self.remaining = rest;
+            self.finished = rest.is_empty();
+
+            if let Some(row) = self.state.row(line, self.finished) {
+                return Some(row);
+            }
+        }
+        None
+    }
+}
+
+/// Cut the input in rows, keeping track of the address
+/// and of the squeezed lines between two rows.
+#[derive(Debug, Clone)]
+pub(crate) struct RowState<'a> {
+    options: &'a Hexdump,
+    /// address of the next row
+    address: u64,
+    /// number of empty cells before the first byte of the next row
+    lead: usize,
+    /// last complete line, empty if the last line was partial
+    previous: Vec<u8>,
+    /// whether the lines are currently being squeezed
+    squeezing: bool,
+    /// styles actually used, depending on the color choice
+    theme: Theme,
+    /// colors available, `None` when the hexdump isn't colored
+    depth: Option<ColorDepth>,
+    /// last bytes of the input, for `ColorScheme::Entropy`
+    window: Option<Window>,
+    /// offset in the input of the first byte of the next row
+    offset: usize,
+    /// highlights of the bytes, walked row by row
+    highlighter: Highlighter<'a>,
+}
+
+impl<'a> RowState<'a> {
+    pub(crate) fn new(options: &'a Hexdump) -> Self {
+        let lead = options.base % options.width as u64;
+
+        RowState {
+            options,
+            address: options.base - lead,
+            lead: lead as usize,
+            previous: Vec::with_capacity(options.width),
+            squeezing: false,
+            theme: options.styles(),
+            depth: options.depth(),
+            window: match options.scheme {
+                ColorScheme::Entropy(size) => Some(Window::new(size)),
+                _ => None,
+            },
+            offset: 0,
+            highlighter: Highlighter::new(&options.highlights),
+        }
+    }
+
+    /// Number of bytes expected for the next row
+    pub(crate) fn capacity(&self) -> usize {
+        self.options.width - self.lead
+    }
+
+    /// Row of the next `bytes`, holding at most `capacity()` bytes.
+    /// Returns `None` for lines hidden after a squeezed row, the `last` line is never squeezed.
+    pub(crate) fn row<'b>(&mut self, bytes: &'b [u8], last: bool) -> Option<Row<'b>> {
+        self.emphasized_row(bytes, last, &[], Style::new())
+    }
+
+    /// Like `row()`, with the bytes marked in `emphasized` displayed with `emphasis`,
+    /// on top of their other styles
+    pub(crate) fn emphasized_row<'b>(
+        &mut self,
+        bytes: &'b [u8],
+        last: bool,
+        emphasized: &[bool],
+        emphasis: Style,
+    ) -> Option<Row<'b>> {
+        let squeezed = self.options.squeeze
+            && self.previous == bytes
+            && !last
+            && !self.highlighted(bytes.len());
+        // even hidden lines go through the entropy window
+        let mut styles = self.byte_styles(bytes);
+        if let Some(depth) = self.depth {
+            let emphasis = emphasis.downgrade(depth);
+            for (style, _) in styles.iter_mut().zip(emphasized).filter(|&(_, &emphasized)| emphasized) {
+                *style = layered(*style, emphasis);
+            }
+        }
+
+        let row = match (squeezed, self.squeezing) {
+            (true, true) => None,
+            (true, false) => Some(Row {
+                address: self.address,
+                lead: self.lead,
+                bytes,
+                hex: String::new(),
+                text: String::new(),
+                squeezed,
+            }),
+            (false, _) => {
+                let (hex, text) = self.render(bytes, &styles);
+                Some(Row {
+                    address: self.address,
+                    lead: self.lead,
+                    bytes,
+                    hex,
+                    text,
+                    squeezed,
+                })
+            }
+        };
+        self.squeezing = squeezed;
+
+        // partial lines are never squeezed
+        self.previous.clear();
+        if bytes.len() == self.options.width {
+            self.previous.extend_from_slice(bytes);
+        }
+
+        self.address = self.address.saturating_add(self.options.width as u64);
+        self.lead = 0;
+        self.offset += bytes.len();
+        row
+    }
+
+    /// Whether any of the next `len` bytes is highlighted
+    pub(crate) fn highlighted(&mut self, len: usize) -> bool {
+        self.highlighter.overlaps(self.offset..self.offset + len)
+    }
+
+    /// Colored hexadecimal and text panels of a row
+    fn render(&self, bytes: &[u8], styles: &[Style]) -> (String, String) {
+        let options = self.options;
+        self.panels(self.lead, bytes.len(), |index, hex, text| {
+            // the colored byte in hexadecimal
+            let (byte, style) = (bytes[index], styles[index]);
+            let glyph = options.classifier.glyph(byte)
+                .unwrap_or_else(|| options.chars.get(byte));
+            let _ = match options.uppercase {
+                true => write!(hex, "{}", style.paint(format_args!("{:02X}", byte))),
+                false => write!(hex, "{}", style.paint(format_args!("{:02x}", byte))),
+            };
+            let _ = write!(text, "{}", style.paint(glyph));
+        })
+    }
+
+    /// Panels of the same width as those of `row`, with `^` under the bytes marked in `marked`
+    pub(crate) fn markers(&self, row: &Row, marked: &[bool]) -> (String, String) {
+        self.panels(row.lead, row.bytes.len().max(marked.len()), |index, hex, text| {
+            let marked = marked.get(index) == Some(&true);
+            hex.push_str(if marked { "^^" } else { "  " });
+            text.push(if marked { '^' } else { ' ' });
+        })
+    }
+
+    /// Hexadecimal and text panels with the separators of the layout, where `cell`
+    /// writes the `len` cells following `lead` empty cells
+    fn panels(
+        &self,
+        lead: usize,
+        len: usize,
+        mut cell: impl FnMut(usize, &mut String, &mut String),
+    ) -> (String, String) {
+        let options = self.options;
+        let theme = &self.theme;
+        let mut hex = String::new();
+        let mut text = String::new();
+
+        for i in 0..options.width {
+            // separator before the cell
+            if i > 0 {
+                match options.layout {
+                    Layout::Hexyl if i % PANEL_WIDTH == 0 => {
+                        let _ = write!(hex, " {} ", theme.separator.paint('│'));
+                        let _ = write!(text, "{}", theme.separator.paint('│'));
+                    }
+                    Layout::Hexyl => hex.push(' '),
+                    Layout::Xxd => {
+                        if options.group != 0 && i % options.group == 0 {
+                            hex.push(' ');
+                        }
+                    }
+                }
+            }
+
+            match i.checked_sub(lead).filter(|&index| index < len) {
+                Some(index) => cell(index, &mut hex, &mut text),
+                // fill with whitespace before the first byte and after the last one
+                None => {
+                    hex.push_str("  ");
+                    text.push(' ');
+                }
+            }
+        }
+        (hex, text)
+    }
+
+    /// Style of each byte in both panels, depending on the color scheme
+    fn byte_styles(&mut self, bytes: &[u8]) -> Vec<Style> {
+        let Some(depth) = self.depth else {
+            return vec![Style::new(); bytes.len()];
+        };
+        // color of an entropy, relative to its maximum
+        let entropy_style = |entropy: f64| {
+            let color = gradient((entropy * 255.0).round() as u8);
+            Style::new().fg(color.downgrade(depth))
+        };
+
+        let styles: Vec<Style> = match self.options.scheme {
+            ColorScheme::Classes => bytes
+                .iter()
+                .map(|&byte| self.theme.category(self.options.classifier.classify(byte)))
+                .collect(),
+            ColorScheme::Gradient => bytes
+                .iter()
+                .map(|&byte| Style::new().fg(gradient(byte).downgrade(depth)))
+                .collect(),
+            ColorScheme::LineEntropy => vec![entropy_style(line_entropy(bytes)); bytes.len()],
+            ColorScheme::Entropy(_) => {
+                let window = self.window.as_mut().expect("the window is created with the state");
+                bytes.iter().map(|&byte| entropy_style(window.push(byte))).collect()
+            }
+        };
+
+        let offset = self.offset;
+        (styles.into_iter().enumerate())
+            .map(|(i, style)| self.highlighter.style(offset + i, style, depth))
+            .collect()
+    }
+}
@@ -0,0 +1,361 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Color, Highlight, Style};
+
+/// Sequence of bytes to search for, where each byte can be a wildcard.
+///
+/// ```
+/// use colored_hexdump::{Color, Hexdump, Pattern, Style};
+///
+/// let bytes = b"\xde\xad\xbe\xef and \xde\xad\x00\xef";
+/// let pattern: Pattern = "DE AD ?? EF".parse().unwrap();
+/// assert_eq!(pattern.find_all(bytes), [0, 9]);
+///
+/// let highlights = pattern.highlights(bytes, Style::new().on(Color::BLUE));
+/// println!("{}", Hexdump::xxd().highlights(highlights).dump(bytes));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Pattern {
+    /// bytes of the pattern, `None` for the wildcards
+    bytes: Vec<Option<u8>>,
+}
+
+impl Pattern {
+    /// Parse hexadecimal bytes, where `??` matches any byte.
+    /// Bytes can be separated by whitespace, but each one is written within a single word.
+    pub fn parse(pattern: &str) -> Result<Pattern, PatternError> {
+        let mut bytes = Vec::new();
+        for word in pattern.split_whitespace() {
+            let digits: Vec<char> = word.chars().collect();
+            if !digits.len().is_multiple_of(2) {
+                return Err(PatternError::InvalidByte(word.to_string()));
+            }
+            for pair in digits.chunks(2) {
+                let pair: String = pair.iter().collect();
+                bytes.push(match pair.as_str() {
+                    "??" => None,
+                    hex if hex.chars().all(|c| c.is_ascii_hexdigit()) => u8::from_str_radix(hex, 16).ok(),
+                    _ => return Err(PatternError::InvalidByte(pair)),
+                });
+            }
+        }
+        if bytes.is_empty() {
+            return Err(PatternError::Empty);
+        }
+        Ok(Pattern { bytes })
+    }
+
+    /// Pattern matching exactly the bytes of `text`
+    pub fn ascii(text: &str) -> Pattern {
+        Pattern::bytes(text.as_bytes())
+    }
+
+    /// Pattern matching exactly `bytes`
+    pub fn bytes(bytes: &[u8]) -> Pattern {
+        Pattern {
+            bytes: bytes.iter().copied().map(Some).collect(),
+        }
+    }
+
+    /// Number of bytes matched by the pattern
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Whether the pattern doesn't match any byte
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Whether the pattern matches the start of `bytes`
+    pub fn matches(&self, bytes: &[u8]) -> bool {
+        bytes.len() >= self.len()
+            && (self.bytes.iter().zip(bytes))
+                .all(|(expected, byte)| expected.is_none_or(|expected| expected == *byte))
+    }
+
+    /// Offsets of all the matches in `bytes`, including the overlapping ones
+    pub fn find_all(&self, bytes: &[u8]) -> Vec<usize> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+        (0..bytes.len())
+            .filter(|&offset| self.matches(&bytes[offset..]))
+            .collect()
+    }
+
+    /// Highlights of all the matches in `bytes` with `style`, labelled with the pattern.
+    /// See `Hexdump::highlights()`.
+    pub fn highlights(&self, bytes: &[u8], style: Style) -> Vec<Highlight> {
+        let label = self.to_string();
+        self.find_all(bytes)
+            .into_iter()
+            .map(|offset| Highlight::new(offset..offset + self.len(), style, label.clone()))
+            .collect()
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = PatternError;
+
+    fn from_str(pattern: &str) -> Result<Pattern, PatternError> {
+        Pattern::parse(pattern)
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, byte) in self.bytes.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            match byte {
+                Some(byte) => write!(f, "{:02X}", byte)?,
+                None => f.write_str("??")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Error returned by `Pattern::parse()`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatternError {
+    /// The pattern doesn't contain any byte
+    Empty,
+    /// The pattern contains something else than a hexadecimal byte or `??`
+    InvalidByte(String),
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatternError::Empty => f.write_str("empty byte pattern"),
+            PatternError::InvalidByte(byte) => write!(f, "invalid byte `{}` in pattern", byte),
+        }
+    }
+}
+
+impl Error for PatternError {}
+
+/// Size of an integer in memory
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntWidth {
+    U16,
+    U32,
+    U64,
+}
+
+impl IntWidth {
+    /// Number of bytes of the integer
+    pub fn bytes(self) -> usize {
+        match self {
+            IntWidth::U16 => 2,
+            IntWidth::U32 => 4,
+            IntWidth::U64 => 8,
+        }
+    }
+}
+
+/// Byte order of an integer in memory
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endian {
+    Little,
+    Big,
+}
+
+/// How an integer is stored in memory, displayed like `u32 LE`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Encoding {
+    pub width: IntWidth,
+    pub endian: Endian,
+}
+
+impl Encoding {
+    /// All the widths, in both byte orders
+    pub const ALL: [Encoding; 6] = [
+        Encoding::new(IntWidth::U16, Endian::Little),
+        Encoding::new(IntWidth::U16, Endian::Big),
+        Encoding::new(IntWidth::U32, Endian::Little),
+        Encoding::new(IntWidth::U32, Endian::Big),
+        Encoding::new(IntWidth::U64, Endian::Little),
+        Encoding::new(IntWidth::U64, Endian::Big),
+    ];
+
+    pub const fn new(width: IntWidth, endian: Endian) -> Self {
+        Encoding { width, endian }
+    }
+
+    /// Bytes of `value` with this encoding, `None` if it doesn't fit in the width
+    pub fn encode(self, value: u64) -> Option<Vec<u8>> {
+        let len = self.width.bytes();
+        if len < 8 && value >> (8 * len) != 0 {
+            return None;
+        }
+        let bytes = match self.endian {
+            Endian::Little => value.to_le_bytes()[..len].to_vec(),
+            Endian::Big => value.to_be_bytes()[8 - len..].to_vec(),
+        };
+        Some(bytes)
+    }
+
+    /// Style of the matches with this encoding: a background for each width and
+    /// endianness, and bold for big endian as the backgrounds merge with 16 colors
+    fn style(self) -> Style {
+        let color = match (self.width, self.endian) {
+            (IntWidth::U16, Endian::Little) => Color::Fixed(24),
+            (IntWidth::U16, Endian::Big) => Color::Fixed(30),
+            (IntWidth::U32, Endian::Little) => Color::Fixed(54),
+            (IntWidth::U32, Endian::Big) => Color::Fixed(90),
+            (IntWidth::U64, Endian::Little) => Color::Fixed(94),
+            (IntWidth::U64, Endian::Big) => Color::Fixed(130),
+        };
+        match self.endian {
+            Endian::Little => Style::new().on(color),
+            Endian::Big => Style::new().on(color).bold(),
+        }
+    }
+}
+
+impl fmt::Display for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let endian = match self.endian {
+            Endian::Little => "LE",
+            Endian::Big => "BE",
+        };
+        write!(f, "u{} {}", 8 * self.width.bytes(), endian)
+    }
+}
+
+/// Occurrence of an integer found by `ValueSearch`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ValueMatch {
+    /// Offset of the first byte of the integer in the input
+    pub offset: usize,
+    /// How the integer is stored at this offset
+    pub encoding: Encoding,
+}
+
+/// Search of an integer stored with any width and byte order.
+///
+/// ```
+/// use colored_hexdump::{Encoding, Endian, Hexdump, IntWidth, ValueMatch, ValueSearch};
+///
+/// let bytes = b"\xff\x12\x34\xff\x34\x12";
+/// let search = ValueSearch::new(0x1234);
+/// let u16_be = Encoding::new(IntWidth::U16, Endian::Big);
+/// assert_eq!(search.find_all(bytes)[0], ValueMatch { offset: 1, encoding: u16_be });
+///
+/// println!("{}", Hexdump::xxd().highlights(search.highlights(bytes)).dump(bytes));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ValueSearch {
+    value: u64,
+    encodings: Vec<Encoding>,
+}
+
+impl ValueSearch {
+    /// Search of `value` with all the encodings it fits in
+    pub fn new(value: u64) -> Self {
+        ValueSearch {
+            value,
+            encodings: Encoding::ALL.to_vec(),
+        }
+    }
+
+    /// Only search the value with `encodings`
+    pub fn encodings(mut self, encodings: impl IntoIterator<Item = Encoding>) -> Self {
+        self.encodings = encodings.into_iter().collect();
+        self
+    }
+
+    /// All the occurrences of the value in `bytes`, by offset.
+    /// An occurrence matching several encodings is listed once for each of them.
+    pub fn find_all(&self, bytes: &[u8]) -> Vec<ValueMatch> {
+        let mut matches: Vec<ValueMatch> = (self.encodings.iter())
+            .filter_map(|&encoding| Some((encoding, Pattern::bytes(&encoding.encode(self.value)?))))
+            .flat_map(|(encoding, pattern)| {
+                (pattern.find_all(bytes).into_iter())
+                    .map(move |offset| ValueMatch { offset, encoding })
+            })
+            .collect();
+        matches.sort_by_key(|found| found.offset);
+        matches
+    }
+
+    /// Highlights of all the occurrences in `bytes`, with a style for each encoding,
+    /// labelled with the value and the encoding. See `Hexdump::highlights()`.
+    pub fn highlights(&self, bytes: &[u8]) -> Vec<Highlight> {
+        (self.find_all(bytes).into_iter())
+            .map(|found| {
+                let label = format!("{:#x} as {}", self.value, found.encoding);
+                let range = found.offset..found.offset + found.encoding.width.bytes();
+                Highlight::new(range, found.encoding.style(), label)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::ColorDepth;
+
+    #[test]
+    fn test_parse() {
+        let pattern = Pattern::parse("DE AD ?? ef").unwrap();
+        assert_eq!(pattern.len(), 4);
+        assert_eq!(pattern.to_string(), "DE AD ?? EF");
+        assert_eq!(Pattern::parse("dead??EF"), Ok(pattern));
+        assert_eq!(Pattern::ascii("Hi").to_string(), "48 69");
+
+        assert_eq!(Pattern::parse(" "), Err(PatternError::Empty));
+        assert_eq!(Pattern::parse("DE A"), Err(PatternError::InvalidByte("A".into())));
+        assert_eq!(Pattern::parse("DE ?A"), Err(PatternError::InvalidByte("?A".into())));
+        assert_eq!(Pattern::parse("+1"), Err(PatternError::InvalidByte("+1".into())));
+        assert_eq!(Pattern::parse("D EA D"), Err(PatternError::InvalidByte("D".into())));
+        assert_eq!(Pattern::parse("DEA D"), Err(PatternError::InvalidByte("DEA".into())));
+    }
+
+    #[test]
+    fn test_find_all() {
+        let pattern = Pattern::parse("AA ?? AA").unwrap();
+        assert_eq!(pattern.find_all(&[0xaa, 0x00, 0xaa, 0x01, 0xaa, 0xaa]), [0, 2]);
+        assert_eq!(pattern.find_all(&[0xaa, 0x00]), []);
+        assert_eq!(Pattern::ascii("ab").find_all(b"abcab"), [0, 3]);
+    }
+
+    #[test]
+    fn test_value_search() {
+        let u16_le = Encoding::new(IntWidth::U16, Endian::Little);
+        let u32_be = Encoding::new(IntWidth::U32, Endian::Big);
+        assert_eq!(u16_le.to_string(), "u16 LE");
+        assert_eq!(u16_le.encode(0x1234), Some(vec![0x34, 0x12]));
+        assert_eq!(u32_be.encode(0x1234), Some(vec![0x00, 0x00, 0x12, 0x34]));
+        assert_eq!(u16_le.encode(0x12345), None);
+
+        let bytes = [0x34, 0x12, 0x00, 0x00, 0x12, 0x34, 0xff];
+        let search = ValueSearch::new(0x1234).encodings([u16_le, u32_be]);
+        assert_eq!(
+            search.find_all(&bytes),
+            [
+                ValueMatch { offset: 0, encoding: u16_le },
+                ValueMatch { offset: 2, encoding: u32_be },
+            ]
+        );
+
+        let highlights = ValueSearch::new(0x1234).highlights(&bytes);
+        let labels: Vec<&str> = highlights.iter().map(|highlight| highlight.label.as_str()).collect();
+        assert_eq!(labels, ["0x1234 as u16 LE", "0x1234 as u32 LE", "0x1234 as u32 BE", "0x1234 as u16 BE"]);
+        assert_eq!(highlights[2].range, 2..6);
+
+        for depth in [ColorDepth::Ansi16, ColorDepth::Ansi256] {
+            let styles: HashSet<Style> = Encoding::ALL.iter().map(|encoding| encoding.style().downgrade(depth)).collect();
+            assert_eq!(styles.len(), Encoding::ALL.len());
+        }
+    }
+}
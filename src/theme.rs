@@ -0,0 +1,588 @@
+use std::env;
+use std::ffi::OsString;
+use std::fmt;
+use std::io::IsTerminal;
+
+use crate::ByteCategory;
+
+/// One of the 16 basic colors, whose actual value depends on the terminal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnsiColor {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+}
+
+impl AnsiColor {
+    /// The 16 colors, in the order of the palette
+    pub const ALL: [AnsiColor; 16] = [
+        AnsiColor::Black,
+        AnsiColor::Red,
+        AnsiColor::Green,
+        AnsiColor::Yellow,
+        AnsiColor::Blue,
+        AnsiColor::Magenta,
+        AnsiColor::Cyan,
+        AnsiColor::White,
+        AnsiColor::BrightBlack,
+        AnsiColor::BrightRed,
+        AnsiColor::BrightGreen,
+        AnsiColor::BrightYellow,
+        AnsiColor::BrightBlue,
+        AnsiColor::BrightMagenta,
+        AnsiColor::BrightCyan,
+        AnsiColor::BrightWhite,
+    ];
+
+    /// Color at `index` in the palette, `None` past 15
+    pub const fn from_index(index: u8) -> Option<AnsiColor> {
+        match index {
+            0..16 => Some(AnsiColor::ALL[index as usize]),
+            _ => None,
+        }
+    }
+
+    /// Index of the color in the palette, from 0 to 15
+    pub const fn index(self) -> u8 {
+        self as u8
+    }
+
+    /// Approximate RGB value of the color, as displayed by xterm
+    fn rgb(self) -> (u8, u8, u8) {
+        ANSI_PALETTE[self.index() as usize]
+    }
+}
+
+/// A terminal color
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    /// One of the 8 basic colors, or of their bright variant
+    Ansi(AnsiColor),
+    /// One of the 256 colors of the extended palette
+    Fixed(u8),
+    /// A 24-bit color, converted to the closest color supported by the terminal
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    pub const BLACK: Color = Color::Ansi(AnsiColor::Black);
+    pub const RED: Color = Color::Ansi(AnsiColor::Red);
+    pub const GREEN: Color = Color::Ansi(AnsiColor::Green);
+    pub const YELLOW: Color = Color::Ansi(AnsiColor::Yellow);
+    pub const BLUE: Color = Color::Ansi(AnsiColor::Blue);
+    pub const MAGENTA: Color = Color::Ansi(AnsiColor::Magenta);
+    pub const CYAN: Color = Color::Ansi(AnsiColor::Cyan);
+    pub const WHITE: Color = Color::Ansi(AnsiColor::White);
+    pub const BRIGHT_BLACK: Color = Color::Ansi(AnsiColor::BrightBlack);
+
+    /// Write the SGR parameters selecting this color, as a foreground or background color
+    fn write_sgr(&self, f: &mut fmt::Formatter<'_>, background: bool) -> fmt::Result {
+        let extended = if background { 48 } else { 38 };
+        match *self {
+            Color::Ansi(color) => match color.index() {
+                n @ 0..8 => write!(f, "{}", n + if background { 40 } else { 30 }),
+                n => write!(f, "{}", n - 8 + if background { 100 } else { 90 }),
+            },
+            Color::Fixed(n) => write!(f, "{};5;{}", extended, n),
+            Color::Rgb(r, g, b) => write!(f, "{};2;{};{};{}", extended, r, g, b),
+        }
+    }
+
+    /// Closest color available with `depth`
+    pub fn downgrade(self, depth: ColorDepth) -> Color {
+        match (self, depth) {
+            (Color::Ansi(_), _) | (_, ColorDepth::TrueColor) => self,
+            (Color::Fixed(_), ColorDepth::Ansi256) => self,
+            (Color::Fixed(n @ 0..16), ColorDepth::Ansi16) => Color::Ansi(AnsiColor::ALL[n as usize]),
+            (Color::Rgb(r, g, b), ColorDepth::Ansi256) => Color::Fixed(closest_fixed(r, g, b)),
+            (color, ColorDepth::Ansi16) => {
+                let rgb = color.rgb();
+                let closest = AnsiColor::ALL.into_iter().min_by_key(|&color| distance(color.rgb(), rgb));
+                Color::Ansi(closest.unwrap_or(AnsiColor::Black))
+            }
+        }
+    }
+
+    /// Approximate RGB value of the color, as displayed by xterm
+    fn rgb(self) -> (u8, u8, u8) {
+        match self {
+            Color::Ansi(color) => color.rgb(),
+            Color::Fixed(n @ 0..16) => ANSI_PALETTE[n as usize],
+            Color::Fixed(n @ 16..232) => {
+                let n = n - 16;
+                let n = n as usize;
+                (CUBE_LEVELS[n / 36], CUBE_LEVELS[n / 6 % 6], CUBE_LEVELS[n % 6])
+            }
+            Color::Fixed(n) => {
+                let grey = 8 + 10 * (n - 232);
+                (grey, grey, grey)
+            }
+            Color::Rgb(r, g, b) => (r, g, b),
+        }
+    }
+}
+
+/// The 16 basic colors, as displayed by xterm
+const ANSI_PALETTE: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// Intensities of each component in the 6×6×6 color cube of the extended palette
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Squared distance between two colors
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let d = |x: u8, y: u8| (x as i32 - y as i32).pow(2) as u32;
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+}
+
+/// Closest color of the extended palette, among the color cube and the greyscale ramp
+fn closest_fixed(r: u8, g: u8, b: u8) -> u8 {
+    let level = |x: u8| {
+        (0..6)
+            .min_by_key(|&i| (CUBE_LEVELS[i] as i32 - x as i32).abs())
+            .unwrap_or(0) as u8
+    };
+    let cube = 16 + 36 * level(r) + 6 * level(g) + level(b);
+
+    let average = (r as u32 + g as u32 + b as u32) / 3;
+    let grey = 232 + (average.saturating_sub(3) / 10).min(23) as u8;
+
+    let rgb = (r, g, b);
+    match distance(Color::Fixed(grey).rgb(), rgb) < distance(Color::Fixed(cube).rgb(), rgb) {
+        true => grey,
+        false => cube,
+    }
+}
+
+/// Colors supported by the terminal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorDepth {
+    /// The 16 basic colors
+    Ansi16,
+    /// The extended palette of 256 colors
+    Ansi256,
+    /// 24-bit colors
+    TrueColor,
+}
+
+impl ColorDepth {
+    /// Colors supported by the terminal, detected from `COLORTERM` and `TERM`
+    pub fn detect() -> ColorDepth {
+        detect_depth(|name| env::var_os(name))
+    }
+}
+
+/// Color depth announced by the environment variables
+fn detect_depth(var: impl Fn(&str) -> Option<OsString>) -> ColorDepth {
+    if var("COLORTERM").is_some_and(|value| value == "truecolor" || value == "24bit") {
+        return ColorDepth::TrueColor;
+    }
+    let term = var("TERM").unwrap_or_default();
+    let term = term.to_string_lossy();
+    match term.as_ref() {
+        "linux" | "ansi" | "cygwin" => ColorDepth::Ansi16,
+        term if term.starts_with("vt") => ColorDepth::Ansi16,
+        _ => ColorDepth::Ansi256,
+    }
+}
+
+/// Colors and attributes of an element of the hexdump
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Style {
+    pub foreground: Option<Color>,
+    pub background: Option<Color>,
+    pub bold: bool,
+}
+
+impl Style {
+    /// Style without any color or attribute
+    pub const fn new() -> Self {
+        Style {
+            foreground: None,
+            background: None,
+            bold: false,
+        }
+    }
+
+    /// Set the foreground color
+    pub const fn fg(mut self, color: Color) -> Self {
+        self.foreground = Some(color);
+        self
+    }
+
+    /// Set the background color
+    pub const fn on(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Make the text bold
+    pub const fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Same style, with colors available with `depth`
+    pub(crate) fn downgrade(self, depth: ColorDepth) -> Style {
+        Style {
+            foreground: self.foreground.map(|color| color.downgrade(depth)),
+            background: self.background.map(|color| color.downgrade(depth)),
+            bold: self.bold,
+        }
+    }
+
+    /// Whether the style doesn't change the text at all
+    pub fn is_plain(&self) -> bool {
+        *self == Style::new()
+    }
+
+    /// Display `content` with this style
+    pub(crate) fn paint<T: fmt::Display>(self, content: T) -> Painted<T> {
+        Painted {
+            style: self,
+            content,
+        }
+    }
+}
+
+/// Content displayed with the escape sequences of a style
+pub(crate) struct Painted<T> {
+    style: Style,
+    content: T,
+}
+
+impl<T: fmt::Display> fmt::Display for Painted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let style = self.style;
+        if style.is_plain() {
+            return write!(f, "{}", self.content);
+        }
+
+        f.write_str("\x1b[")?;
+        let mut separator = "";
+        if style.bold {
+            f.write_str("1")?;
+            separator = ";";
+        }
+        if let Some(color) = style.foreground {
+            f.write_str(separator)?;
+            color.write_sgr(f, false)?;
+            separator = ";";
+        }
+        if let Some(color) = style.background {
+            f.write_str(separator)?;
+            color.write_sgr(f, true)?;
+        }
+        write!(f, "m{}\x1b[0m", self.content)
+    }
+}
+
+/// Whether the hexdump is colored
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorChoice {
+    /// Always use the colors of the theme
+    #[default]
+    Always,
+    /// Never color the hexdump: no escape sequence is written, the layout stays the same
+    Never,
+    /// Color the hexdump only when written to a terminal, and when the environment allows it:
+    /// `NO_COLOR`, `CLICOLOR=0` and `TERM=dumb` disable the colors, `CLICOLOR_FORCE` enables them.
+    Auto,
+}
+
+impl ColorChoice {
+    /// Resolve `Auto` to `Always` or `Never`, for an output written to `stream`
+    pub fn resolve(self, stream: &impl IsTerminal) -> ColorChoice {
+        match self {
+            ColorChoice::Auto => ColorChoice::auto(stream.is_terminal()),
+            choice => choice,
+        }
+    }
+
+    /// `Always` or `Never`, from the environment and whether the output is a `terminal`
+    pub(crate) fn auto(terminal: bool) -> ColorChoice {
+        match auto_colors(|name| env::var_os(name), terminal) {
+            true => ColorChoice::Always,
+            false => ColorChoice::Never,
+        }
+    }
+}
+
+/// Whether colors are enabled, given the environment variables and if the output is a terminal
+fn auto_colors(var: impl Fn(&str) -> Option<OsString>, terminal: bool) -> bool {
+    let set = |name| var(name).is_some_and(|value| !value.is_empty());
+
+    if var("CLICOLOR_FORCE").is_some_and(|value| !value.is_empty() && value != "0") {
+        return true;
+    }
+    if set("NO_COLOR") || var("CLICOLOR").is_some_and(|value| value == "0") {
+        return false;
+    }
+    if var("TERM").is_some_and(|term| term == "dumb") {
+        return false;
+    }
+    terminal
+}
+
+/// How the color of each byte is chosen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorScheme {
+    /// Color of the class of the byte in the theme: null, whitespace, printable...
+    #[default]
+    Classes,
+    /// Color taken from the value of the byte on a continuous gradient,
+    /// to spot patterns in compressed or encrypted data
+    Gradient,
+    /// Color taken from the Shannon entropy of each line on the gradient,
+    /// all the bytes of a line share the same color
+    LineEntropy,
+    /// Color taken from the Shannon entropy on the gradient, computed over the
+    /// given number of bytes up to each byte, to spot compressed or encrypted regions
+    Entropy(usize),
+}
+
+/// Colors of the gradient, evenly spaced from 0x00 to 0xff
+const GRADIENT_STOPS: [(u8, u8, u8); 5] = [
+    (90, 60, 160),
+    (40, 130, 240),
+    (30, 210, 140),
+    (250, 200, 40),
+    (220, 40, 30),
+];
+
+/// Color of `byte` in the gradient scheme
+pub(crate) fn gradient(byte: u8) -> Color {
+    let segments = GRADIENT_STOPS.len() - 1;
+    let position = byte as usize * segments;
+    // index of the segment, and position inside it, out of 255
+    let (index, offset) = match position / 255 {
+        index if index == segments => (index - 1, 255),
+        index => (index, position % 255),
+    };
+
+    let (start, end) = (GRADIENT_STOPS[index], GRADIENT_STOPS[index + 1]);
+    let mix = |a: u8, b: u8| (a as usize * (255 - offset) + b as usize * offset).div_ceil(255) as u8;
+    Color::Rgb(mix(start.0, end.0), mix(start.1, end.1), mix(start.2, end.2))
+}
+
+/// Styles of the elements of a hexdump
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Theme {
+    /// Null bytes
+    pub null: Style,
+    /// ASCII whitespace: tab, line feed, form feed, carriage return and space
+    pub whitespace: Style,
+    /// Printable ASCII characters
+    pub printable: Style,
+    /// Other ASCII characters
+    pub non_printable: Style,
+    /// Bytes of 0x80 and above
+    pub high: Style,
+    /// Bytes classified as `ByteCategory::Special`
+    pub special: Style,
+    /// Address column
+    pub address: Style,
+    /// Borders of the hexyl layout
+    pub border: Style,
+    /// Separators between the panels of the hexyl layout
+    pub separator: Style,
+}
+
+impl Theme {
+    /// Default colors, for dark terminals
+    pub const DEFAULT: Theme = Theme {
+        null: Style::new().fg(Color::Fixed(242)),
+        whitespace: Style::new().fg(Color::GREEN),
+        printable: Style::new().fg(Color::CYAN),
+        non_printable: Style::new().fg(Color::MAGENTA),
+        high: Style::new().fg(Color::YELLOW),
+        special: Style::new().fg(Color::RED).bold(),
+        address: Style::new().fg(Color::Fixed(242)),
+        border: Style::new(),
+        separator: Style::new(),
+    };
+
+    /// Darker colors, readable on light terminals
+    pub const LIGHT: Theme = Theme {
+        null: Style::new().fg(Color::Fixed(248)),
+        whitespace: Style::new().fg(Color::Fixed(28)),
+        printable: Style::new().fg(Color::Fixed(25)),
+        non_printable: Style::new().fg(Color::Fixed(127)),
+        high: Style::new().fg(Color::Fixed(130)),
+        special: Style::new().fg(Color::Fixed(160)).bold(),
+        address: Style::new().fg(Color::Fixed(244)),
+        border: Style::new().fg(Color::Fixed(250)),
+        separator: Style::new().fg(Color::Fixed(250)),
+    };
+
+    /// Colors of hexyl
+    pub const HEXYL: Theme = Theme {
+        null: Style::new().fg(Color::BRIGHT_BLACK),
+        whitespace: Style::new().fg(Color::GREEN),
+        printable: Style::new().fg(Color::CYAN),
+        non_printable: Style::new().fg(Color::GREEN),
+        high: Style::new().fg(Color::YELLOW),
+        special: Style::new().fg(Color::RED),
+        address: Style::new().fg(Color::BRIGHT_BLACK),
+        border: Style::new().fg(Color::BRIGHT_BLACK),
+        separator: Style::new().fg(Color::BRIGHT_BLACK),
+    };
+
+    /// No colors at all, see `ColorChoice::Never`
+    pub const PLAIN: Theme = Theme {
+        null: Style::new(),
+        whitespace: Style::new(),
+        printable: Style::new(),
+        non_printable: Style::new(),
+        high: Style::new(),
+        special: Style::new(),
+        address: Style::new(),
+        border: Style::new(),
+        separator: Style::new(),
+    };
+
+    /// Same theme, with colors available with `depth`
+    pub(crate) fn downgrade(&self, depth: ColorDepth) -> Theme {
+        Theme {
+            null: self.null.downgrade(depth),
+            whitespace: self.whitespace.downgrade(depth),
+            printable: self.printable.downgrade(depth),
+            non_printable: self.non_printable.downgrade(depth),
+            high: self.high.downgrade(depth),
+            special: self.special.downgrade(depth),
+            address: self.address.downgrade(depth),
+            border: self.border.downgrade(depth),
+            separator: self.separator.downgrade(depth),
+        }
+    }
+
+    /// Style of the bytes of `category`
+    pub(crate) fn category(&self, category: ByteCategory) -> Style {
+        match category {
+            ByteCategory::Null => self.null,
+            ByteCategory::Whitespace => self.whitespace,
+            ByteCategory::Printable => self.printable,
+            ByteCategory::NonPrintable => self.non_printable,
+            ByteCategory::High => self.high,
+            ByteCategory::Special => self.special,
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::DEFAULT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paint() {
+        assert_eq!(Style::new().paint("ab").to_string(), "ab");
+        assert_eq!(Style::new().fg(Color::RED).paint("ab").to_string(), "\x1b[31mab\x1b[0m");
+        assert_eq!(
+            Style::new().fg(Color::Fixed(242)).on(Color::Ansi(AnsiColor::BrightBlue)).bold().paint("ab").to_string(),
+            "\x1b[1;38;5;242;104mab\x1b[0m"
+        );
+        assert_eq!(
+            Style::new().fg(Color::Rgb(255, 128, 0)).paint("ab").to_string(),
+            "\x1b[38;2;255;128;0mab\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_ansi_color() {
+        for (index, color) in AnsiColor::ALL.into_iter().enumerate() {
+            assert_eq!(color.index() as usize, index);
+            assert_eq!(AnsiColor::from_index(index as u8), Some(color));
+        }
+        assert_eq!(AnsiColor::from_index(16), None);
+        let bright_white = Style::new().on(Color::Ansi(AnsiColor::BrightWhite));
+        assert_eq!(bright_white.paint("ab").to_string(), "\x1b[107mab\x1b[0m");
+    }
+
+    #[test]
+    fn test_downgrade() {
+        let orange = Color::Rgb(255, 135, 0);
+        assert_eq!(orange.downgrade(ColorDepth::TrueColor), orange);
+        assert_eq!(orange.downgrade(ColorDepth::Ansi256), Color::Fixed(208));
+        assert_eq!(orange.downgrade(ColorDepth::Ansi16), Color::YELLOW);
+
+        assert_eq!(Color::Rgb(128, 128, 128).downgrade(ColorDepth::Ansi256), Color::Fixed(244));
+        assert_eq!(Color::Rgb(0, 0, 0).downgrade(ColorDepth::Ansi256), Color::Fixed(16));
+        assert_eq!(Color::Fixed(242).downgrade(ColorDepth::Ansi256), Color::Fixed(242));
+        assert_eq!(Color::Fixed(242).downgrade(ColorDepth::Ansi16), Color::BRIGHT_BLACK);
+        assert_eq!(Color::Fixed(9).downgrade(ColorDepth::Ansi16), Color::Ansi(AnsiColor::BrightRed));
+        assert_eq!(Color::CYAN.downgrade(ColorDepth::Ansi16), Color::CYAN);
+    }
+
+    #[test]
+    fn test_gradient() {
+        assert_eq!(gradient(0x00), Color::Rgb(90, 60, 160));
+        assert_eq!(gradient(0xff), Color::Rgb(220, 40, 30));
+        assert_ne!(gradient(0x10), gradient(0x11));
+    }
+
+    #[test]
+    fn test_detect_depth() {
+        assert_eq!(detect_depth(env(&[("COLORTERM", "truecolor")])), ColorDepth::TrueColor);
+        assert_eq!(detect_depth(env(&[("COLORTERM", "24bit"), ("TERM", "linux")])), ColorDepth::TrueColor);
+        assert_eq!(detect_depth(env(&[("TERM", "xterm-256color")])), ColorDepth::Ansi256);
+        assert_eq!(detect_depth(env(&[("TERM", "linux")])), ColorDepth::Ansi16);
+        assert_eq!(detect_depth(env(&[("TERM", "vt100")])), ColorDepth::Ansi16);
+        assert_eq!(detect_depth(env(&[])), ColorDepth::Ansi256);
+    }
+
+    /// Environment made of `vars`
+    fn env(vars: &'static [(&'static str, &'static str)]) -> impl Fn(&str) -> Option<OsString> {
+        move |name| {
+            vars.iter()
+                .find(|(var, _)| *var == name)
+                .map(|(_, value)| OsString::from(value))
+        }
+    }
+
+    #[test]
+    fn test_auto_colors() {
+        assert!(auto_colors(env(&[("TERM", "xterm")]), true));
+        assert!(!auto_colors(env(&[("TERM", "xterm")]), false));
+        assert!(!auto_colors(env(&[("TERM", "dumb")]), true));
+        assert!(!auto_colors(env(&[("NO_COLOR", "1")]), true));
+        assert!(auto_colors(env(&[("NO_COLOR", "")]), true));
+        assert!(!auto_colors(env(&[("CLICOLOR", "0")]), true));
+        assert!(auto_colors(env(&[("CLICOLOR_FORCE", "1"), ("NO_COLOR", "1")]), false));
+        assert!(!auto_colors(env(&[("CLICOLOR_FORCE", "0")]), false));
+    }
+}